@@ -0,0 +1,4 @@
+//! Helpers shared by the OtusFX programs
+
+pub mod migration;
+pub mod rate_limit;
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+/// Check that `account` holds a `T` owned by `program_id`, whatever its layout
+///
+/// Fails with `invalid` (the calling program's migration error) otherwise.
+pub fn check_migration_account<T, E>(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+    invalid: E,
+) -> Result<()>
+where
+    T: Discriminator,
+    E: Into<Error>,
+{
+    let data = account.try_borrow_data()?;
+    if account.owner != program_id || data.len() < 8 || data[..8] != T::DISCRIMINATOR {
+        return Err(invalid.into());
+    }
+    Ok(())
+}
+
+/// Top up rent from `payer` and realloc `account` to `new_len`
+///
+/// Bytes added by the realloc are zeroed, so appended fields (including `version`)
+/// read as zero until the migration fills them in. Accounts already at least
+/// `new_len` long are left as they are.
+pub fn grow_for_migration<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let required = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if required > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, required)?;
+    }
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
+
+/// Load a program account written with an older layout for migration
+///
+/// Checks ownership and discriminator, grows the account to `new_len` and reads it
+/// with the current layout. Only valid while every layout change since the account
+/// was written appended fields or carved them from reserved space.
+pub fn load_for_migration<'info, T, E>(
+    account: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    new_len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    invalid: E,
+) -> Result<T>
+where
+    T: AccountDeserialize + Discriminator,
+    E: Into<Error>,
+{
+    check_migration_account::<T, E>(account, program_id, invalid)?;
+    grow_for_migration(account, new_len, payer, system_program)?;
+
+    let data = account.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Read an account's data (after the discriminator) with a legacy layout `L`
+///
+/// Call `check_migration_account` first; trailing bytes beyond `L` are ignored.
+pub fn load_legacy<L: AnchorDeserialize>(account: &AccountInfo) -> Result<L> {
+    let data = account.try_borrow_data()?;
+    L::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Write a migrated account back in the current layout
+pub fn store_migrated<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
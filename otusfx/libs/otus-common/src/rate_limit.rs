@@ -0,0 +1,32 @@
+/// Calculate a token bucket's level after refilling linearly for `elapsed_secs`
+/// A full refill takes `window_secs`; the level never exceeds `capacity`
+pub fn calculate_bucket_level(available: u64, capacity: u64, window_secs: i64, elapsed_secs: i64) -> u64 {
+    if window_secs <= 0 || elapsed_secs >= window_secs {
+        return capacity;
+    }
+    if elapsed_secs <= 0 {
+        return available.min(capacity);
+    }
+
+    let refill = (capacity as u128) * (elapsed_secs as u128) / (window_secs as u128);
+    ((available as u128) + refill).min(capacity as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_level() {
+        // Half a window refills half the capacity
+        assert_eq!(calculate_bucket_level(0, 1_000, 3_600, 1_800), 500);
+        // Never exceeds capacity
+        assert_eq!(calculate_bucket_level(900, 1_000, 3_600, 1_800), 1_000);
+        // A full window refills completely
+        assert_eq!(calculate_bucket_level(0, 1_000, 3_600, 7_200), 1_000);
+        // No time elapsed leaves the level unchanged
+        assert_eq!(calculate_bucket_level(250, 1_000, 3_600, 0), 250);
+        // A disabled window is always full
+        assert_eq!(calculate_bucket_level(0, 1_000, 0, 1), 1_000);
+    }
+}
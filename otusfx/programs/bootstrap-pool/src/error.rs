@@ -40,4 +40,16 @@ pub enum BootstrapError {
 
     #[msg("Unauthorized")]
     Unauthorized,
+
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Account is not a migratable account of this program")]
+    InvalidMigrationAccount,
+
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Scops NFT mint was not issued by the bootstrap pool")]
+    InvalidScopsMint,
 }
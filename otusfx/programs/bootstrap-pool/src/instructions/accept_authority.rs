@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::BootstrapError;
+use crate::state::BootstrapConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed authority (a multisig or governance PDA signs via CPI)
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BootstrapConfig::SEEDS_PREFIX],
+        bump = bootstrap_config.bump,
+        constraint = bootstrap_config.pending_authority == new_authority.key() @ BootstrapError::InvalidPendingAuthority
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.bootstrap_config;
+
+    let previous_authority = config.authority;
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = Pubkey::default();
+
+    emit_cpi!(AuthorityTransferred {
+        previous_authority,
+        new_authority: config.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Bootstrap authority transferred");
+    msg!("Previous: {}, New: {}", previous_authority, config.authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use crate::error::BootstrapError;
 use crate::state::{BootstrapConfig, UserDeposit};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimOtusRewards<'info> {
     #[account(mut)]
@@ -81,9 +82,22 @@ pub fn handler(ctx: Context<ClaimOtusRewards>) -> Result<()> {
     // Mark as claimed
     user_deposit.has_claimed_otus = true;
 
+    emit_cpi!(OtusClaimed {
+        user: ctx.accounts.user.key(),
+        amount: otus_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("OTUS rewards claimed");
     msg!("User: {}", ctx.accounts.user.key());
     msg!("Amount: {} OTUS", otus_amount);
 
     Ok(())
 }
+
+#[event]
+pub struct OtusClaimed {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
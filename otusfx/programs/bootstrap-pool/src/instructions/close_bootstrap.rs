@@ -4,15 +4,13 @@ use crate::state::BootstrapConfig;
 
 #[derive(Accounts)]
 pub struct CloseBootstrap<'info> {
-    #[account(
-        constraint = authority.key() == bootstrap_config.authority @ BootstrapError::Unauthorized
-    )]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [BootstrapConfig::SEEDS_PREFIX],
-        bump = bootstrap_config.bump
+        bump = bootstrap_config.bump,
+        constraint = bootstrap_config.authority == authority.key() @ BootstrapError::Unauthorized
     )]
     pub bootstrap_config: Account<'info, BootstrapConfig>,
 }
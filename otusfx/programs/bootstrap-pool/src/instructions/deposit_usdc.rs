@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::error::BootstrapError;
-use crate::state::{BootstrapConfig, UserDeposit, StablecoinType};
+use crate::state::{BootstrapConfig, UserDeposit, StablecoinType, ScopsTier};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(stablecoin_type: StablecoinType)]
 pub struct DepositUsdc<'info> {
@@ -107,6 +108,16 @@ pub fn handler(ctx: Context<DepositUsdc>, stablecoin_type: StablecoinType, amoun
         }
     }
 
+    emit_cpi!(BootstrapDeposit {
+        user: ctx.accounts.user.key(),
+        stablecoin_type,
+        amount,
+        total_usd_value: user_deposit.total_usd_value,
+        otus_allocation: user_deposit.otus_allocation,
+        scops_tier: user_deposit.scops_tier,
+        timestamp: clock.unix_timestamp,
+    });
+
     let token_name = match stablecoin_type {
         StablecoinType::USDC => "USDC",
         StablecoinType::USD1 => "USD1",
@@ -121,3 +132,14 @@ pub fn handler(ctx: Context<DepositUsdc>, stablecoin_type: StablecoinType, amoun
 
     Ok(())
 }
+
+#[event]
+pub struct BootstrapDeposit {
+    pub user: Pubkey,
+    pub stablecoin_type: StablecoinType,
+    pub amount: u64,
+    pub total_usd_value: u64,
+    pub otus_allocation: u64,
+    pub scops_tier: ScopsTier,
+    pub timestamp: i64,
+}
@@ -67,6 +67,7 @@ pub fn handler(
     require!(bootstrap_end > bootstrap_start, BootstrapError::BootstrapNotActive);
 
     config.authority = ctx.accounts.authority.key();
+    config.pending_authority = Pubkey::default();
     config.usdc_vault = ctx.accounts.usdc_vault.key();
     config.usd1_vault = ctx.accounts.usd1_vault.key();
     config.otus_vault = ctx.accounts.otus_vault.key();
@@ -78,6 +79,7 @@ pub fn handler(
     config.otus_distribution_rate = otus_distribution_rate;
     config.total_participants = 0;
     config.bump = ctx.bumps.bootstrap_config;
+    config.version = BootstrapConfig::VERSION;
 
     msg!("Bootstrap pool initialized");
     msg!("Start: {}, End: {}", bootstrap_start, bootstrap_end);
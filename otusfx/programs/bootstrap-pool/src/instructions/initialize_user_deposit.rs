@@ -38,6 +38,7 @@ pub fn handler(ctx: Context<InitializeUserDeposit>) -> Result<()> {
     user_deposit.has_claimed_otus = false;
     user_deposit.has_minted_scops = false;
     user_deposit.bump = ctx.bumps.user_deposit;
+    user_deposit.version = UserDeposit::VERSION;
 
     msg!("User deposit account initialized");
     msg!("User: {}", ctx.accounts.user.key());
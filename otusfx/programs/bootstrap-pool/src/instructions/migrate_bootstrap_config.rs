@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::error::BootstrapError;
+use crate::state::{BootstrapConfig, BootstrapConfigV0};
+use otus_common::migration::{
+    check_migration_account, grow_for_migration, load_for_migration, load_legacy, store_migrated,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBootstrapConfig<'info> {
+    /// Bootstrap config written with an older layout
+    /// CHECK: Owner and discriminator are validated in the migration helpers
+    #[account(
+        mut,
+        seeds = [BootstrapConfig::SEEDS_PREFIX],
+        bump
+    )]
+    pub bootstrap_config: UncheckedAccount<'info>,
+
+    /// Current authority (config migrations are admin only); pays any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateBootstrapConfig>) -> Result<()> {
+    let info = ctx.accounts.bootstrap_config.to_account_info();
+
+    // v0 (baseline) configs predate the version byte, and later layouts inserted fields
+    // mid-struct, so they are recognised by length and converted field by field
+    let mut bootstrap_config: BootstrapConfig = if info.data_len() == BootstrapConfigV0::LEN {
+        check_migration_account::<BootstrapConfig, _>(
+            &info,
+            &crate::ID,
+            BootstrapError::InvalidMigrationAccount,
+        )?;
+        let legacy: BootstrapConfigV0 = load_legacy(&info)?;
+        grow_for_migration(
+            &info,
+            BootstrapConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+        legacy.migrate()
+    } else {
+        load_for_migration(
+            &info,
+            &crate::ID,
+            BootstrapConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            BootstrapError::InvalidMigrationAccount,
+        )?
+    };
+
+    // Only the authority recorded in the account may migrate it
+    require!(
+        bootstrap_config.authority == ctx.accounts.payer.key(),
+        BootstrapError::Unauthorized
+    );
+
+    let from_version = bootstrap_config.version;
+    require!(from_version < BootstrapConfig::VERSION, BootstrapError::AccountAlreadyMigrated);
+
+    // v0 -> v1: pending_authority inserted, version and reserved appended (see BootstrapConfigV0)
+    bootstrap_config.version = BootstrapConfig::VERSION;
+
+    store_migrated(&info, &bootstrap_config)?;
+
+    msg!("Bootstrap config migrated");
+    msg!("Account: {}", info.key());
+    msg!("Version: {} -> {}", from_version, BootstrapConfig::VERSION);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use mpl_token_metadata::accounts::Metadata;
+use crate::error::BootstrapError;
+use crate::state::{BootstrapConfig, UserDeposit};
+use otus_common::migration::{load_for_migration, store_migrated};
+
+#[derive(Accounts)]
+pub struct MigrateUserDeposit<'info> {
+    /// User deposit written with an older layout
+    /// CHECK: Owner and discriminator are validated in load_for_migration
+    #[account(mut)]
+    pub user_deposit: UncheckedAccount<'info>,
+
+    /// Pays any additional rent (anyone can migrate)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Scops NFT mint already issued to this depositor (needed when minted; the payer
+    /// must then be the depositor)
+    pub scops_nft_mint: Option<Account<'info, Mint>>,
+
+    /// Metaplex metadata of `scops_nft_mint`
+    /// CHECK: Address, owner and update authority are validated in the handler
+    pub scops_metadata: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<MigrateUserDeposit>) -> Result<()> {
+    let info = ctx.accounts.user_deposit.to_account_info();
+    let mut user_deposit: UserDeposit = load_for_migration(
+        &info,
+        &crate::ID,
+        UserDeposit::LEN,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        BootstrapError::InvalidMigrationAccount,
+    )?;
+
+    let from_version = user_deposit.version;
+    require!(from_version < UserDeposit::VERSION, BootstrapError::AccountAlreadyMigrated);
+
+    // v0 -> v1: version, scops_mint and reserved padding appended (zeroed by realloc);
+    // scops_mint is backfilled for depositors who already minted
+    if user_deposit.has_minted_scops {
+        user_deposit.scops_mint = verify_scops_mint(ctx.accounts, &user_deposit)?;
+    }
+    user_deposit.version = UserDeposit::VERSION;
+
+    store_migrated(&info, &user_deposit)?;
+
+    msg!("User deposit migrated");
+    msg!("Account: {}", info.key());
+    msg!("Version: {} -> {}", from_version, UserDeposit::VERSION);
+
+    Ok(())
+}
+
+/// Check the NFT passed for a minted deposit was issued by this program and return its mint
+///
+/// Metaplex moves the mint authority to the master edition, so the bootstrap config is
+/// recognised as the metadata's update authority instead.
+fn verify_scops_mint(accounts: &MigrateUserDeposit, user_deposit: &UserDeposit) -> Result<Pubkey> {
+    let (Some(mint), Some(metadata)) = (&accounts.scops_nft_mint, &accounts.scops_metadata) else {
+        return err!(BootstrapError::InvalidScopsMint);
+    };
+    require!(
+        accounts.payer.key() == user_deposit.user,
+        BootstrapError::Unauthorized
+    );
+    require!(
+        metadata.key() == Metadata::find_pda(&mint.key()).0
+            && *metadata.owner == mpl_token_metadata::ID,
+        BootstrapError::InvalidScopsMint
+    );
+
+    let data = Metadata::try_from(&metadata.to_account_info())
+        .map_err(|_| error!(BootstrapError::InvalidScopsMint))?;
+    let (bootstrap_config, _) =
+        Pubkey::find_program_address(&[BootstrapConfig::SEEDS_PREFIX], &crate::ID);
+    require!(
+        data.mint == mint.key()
+            && data.update_authority == bootstrap_config
+            && mint.decimals == 0
+            && mint.supply == 1,
+        BootstrapError::InvalidScopsMint
+    );
+
+    Ok(mint.key())
+}
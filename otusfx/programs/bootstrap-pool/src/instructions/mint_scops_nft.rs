@@ -8,6 +8,7 @@ use mpl_token_metadata::types::{PrintSupply, TokenStandard};
 use crate::error::BootstrapError;
 use crate::state::{BootstrapConfig, UserDeposit, ScopsTier};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MintScopsNft<'info> {
     #[account(mut)]
@@ -121,8 +122,16 @@ pub fn handler(ctx: Context<MintScopsNft>) -> Result<()> {
         .print_supply(PrintSupply::Zero)
         .invoke_signed(signer_seeds)?;
 
-    // Mark as minted
+    // Mark as minted and record the mint so holders can prove the NFT came from this deposit
     user_deposit.has_minted_scops = true;
+    user_deposit.scops_mint = ctx.accounts.scops_nft_mint.key();
+
+    emit_cpi!(ScopsMinted {
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.scops_nft_mint.key(),
+        tier: user_deposit.scops_tier,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Scops NFT minted");
     msg!("User: {}", ctx.accounts.user.key());
@@ -131,3 +140,11 @@ pub fn handler(ctx: Context<MintScopsNft>) -> Result<()> {
 
     Ok(())
 }
+
+#[event]
+pub struct ScopsMinted {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub tier: ScopsTier,
+    pub timestamp: i64,
+}
@@ -5,6 +5,10 @@ pub mod withdraw_usdc;
 pub mod claim_otus_rewards;
 pub mod mint_scops_nft;
 pub mod close_bootstrap;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod migrate_bootstrap_config;
+pub mod migrate_user_deposit;
 
 pub use initialize_bootstrap::*;
 pub use initialize_user_deposit::*;
@@ -13,3 +17,7 @@ pub use withdraw_usdc::*;
 pub use claim_otus_rewards::*;
 pub use mint_scops_nft::*;
 pub use close_bootstrap::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use migrate_bootstrap_config::*;
+pub use migrate_user_deposit::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::error::BootstrapError;
+use crate::state::BootstrapConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Current authority (a multisig or governance PDA signs via CPI)
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BootstrapConfig::SEEDS_PREFIX],
+        bump = bootstrap_config.bump,
+        constraint = bootstrap_config.authority == authority.key() @ BootstrapError::Unauthorized
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.bootstrap_config;
+
+    // Proposing the default pubkey cancels a pending transfer
+    config.pending_authority = new_authority;
+
+    emit_cpi!(AuthorityProposed {
+        current_authority: config.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Bootstrap authority transfer proposed");
+    msg!("Current: {}, Pending: {}", config.authority, new_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
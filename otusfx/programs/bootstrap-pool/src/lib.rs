@@ -58,4 +58,25 @@ pub mod bootstrap_pool {
     pub fn close_bootstrap(ctx: Context<CloseBootstrap>) -> Result<()> {
         instructions::close_bootstrap::handler(ctx)
     }
+
+    /// Propose a new bootstrap authority (step 1 of 2, current authority only)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed bootstrap authority (step 2 of 2, pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Grow a bootstrap config to the current layout version (admin only)
+    pub fn migrate_bootstrap_config(ctx: Context<MigrateBootstrapConfig>) -> Result<()> {
+        instructions::migrate_bootstrap_config::handler(ctx)
+    }
+
+    /// Grow a user deposit to the current layout version (permissionless, except that
+    /// deposits which already minted a Scops NFT must be migrated by the depositor)
+    pub fn migrate_user_deposit(ctx: Context<MigrateUserDeposit>) -> Result<()> {
+        instructions::migrate_user_deposit::handler(ctx)
+    }
 }
@@ -3,8 +3,10 @@ use anchor_lang::prelude::*;
 /// Bootstrap pool configuration
 #[account]
 pub struct BootstrapConfig {
-    /// Protocol authority
+    /// Protocol authority (may be a multisig / governance PDA)
     pub authority: Pubkey,
+    /// Proposed new authority awaiting acceptance (default pubkey if none)
+    pub pending_authority: Pubkey,
     /// USDC vault for deposits
     pub usdc_vault: Pubkey,
     /// USD1 vault for deposits
@@ -27,11 +29,16 @@ pub struct BootstrapConfig {
     pub total_participants: u32,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 128],
 }
 
 impl BootstrapConfig {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        32 + // pending_authority
         32 + // usdc_vault
         32 + // usd1_vault
         32 + // otus_vault
@@ -42,7 +49,12 @@ impl BootstrapConfig {
         1 + // is_active
         8 + // otus_distribution_rate
         4 + // total_participants
-        1; // bump
+        1 + // bump
+        1 + // version
+        128; // reserved
 
     pub const SEEDS_PREFIX: &'static [u8] = b"bootstrap_config";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
 }
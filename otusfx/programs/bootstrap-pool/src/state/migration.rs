@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use super::BootstrapConfig;
+
+/// BootstrapConfig as first deployed (before the version byte existed)
+///
+/// pending_authority was later inserted after `authority`, so these accounts cannot
+/// be read with the current layout; they are recognised by their length.
+#[derive(AnchorDeserialize)]
+pub struct BootstrapConfigV0 {
+    pub authority: Pubkey,
+    pub usdc_vault: Pubkey,
+    pub usd1_vault: Pubkey,
+    pub otus_vault: Pubkey,
+    pub total_deposited_usdc: u64,
+    pub total_deposited_usd1: u64,
+    pub bootstrap_start: i64,
+    pub bootstrap_end: i64,
+    pub is_active: bool,
+    pub otus_distribution_rate: u64,
+    pub total_participants: u32,
+    pub bump: u8,
+}
+
+impl BootstrapConfigV0 {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // usdc_vault
+        32 + // usd1_vault
+        32 + // otus_vault
+        8 + // total_deposited_usdc
+        8 + // total_deposited_usd1
+        8 + // bootstrap_start
+        8 + // bootstrap_end
+        1 + // is_active
+        8 + // otus_distribution_rate
+        4 + // total_participants
+        1; // bump
+
+    /// Convert to the current layout at version 0 (no authority transfer pending)
+    pub fn migrate(self) -> BootstrapConfig {
+        BootstrapConfig {
+            authority: self.authority,
+            pending_authority: Pubkey::default(),
+            usdc_vault: self.usdc_vault,
+            usd1_vault: self.usd1_vault,
+            otus_vault: self.otus_vault,
+            total_deposited_usdc: self.total_deposited_usdc,
+            total_deposited_usd1: self.total_deposited_usd1,
+            bootstrap_start: self.bootstrap_start,
+            bootstrap_end: self.bootstrap_end,
+            is_active: self.is_active,
+            otus_distribution_rate: self.otus_distribution_rate,
+            total_participants: self.total_participants,
+            bump: self.bump,
+            version: 0,
+            reserved: [0; 128],
+        }
+    }
+}
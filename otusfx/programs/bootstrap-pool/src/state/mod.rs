@@ -1,8 +1,10 @@
 pub mod config;
 pub mod user_deposit;
+pub mod migration;
 
 pub use config::*;
 pub use user_deposit::*;
+pub use migration::*;
 
 use anchor_lang::prelude::*;
 
@@ -57,6 +57,12 @@ pub struct UserDeposit {
     pub has_minted_scops: bool,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Scops NFT mint issued to this depositor (default until minted)
+    pub scops_mint: Pubkey,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
 }
 
 impl UserDeposit {
@@ -70,10 +76,16 @@ impl UserDeposit {
         1 + // scops_tier (enum)
         1 + // has_claimed_otus
         1 + // has_minted_scops
-        1; // bump
+        1 + // bump
+        1 + // version
+        32 + // scops_mint
+        32; // reserved
 
     pub const SEEDS_PREFIX: &'static [u8] = b"user_deposit";
 
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
     /// Update allocation and tier based on new deposit
     pub fn update_from_deposit(&mut self, usdc_amount: u64, usd1_amount: u64, otus_rate: u64, clock: &Clock) {
         if self.total_usd_value == 0 {
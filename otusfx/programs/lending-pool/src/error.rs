@@ -58,4 +58,43 @@ pub enum LendingError {
 
     #[msg("Invalid amount - must be greater than zero")]
     InvalidAmount,
+
+    #[msg("Unauthorized - Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Unauthorized - Only guardian can pause")]
+    InvalidGuardian,
+
+    #[msg("Lending pool is paused")]
+    PoolPaused,
+
+    #[msg("Timelock delay below minimum")]
+    InvalidTimelockDelay,
+
+    #[msg("Config proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Global settlement is active")]
+    GlobalSettlementActive,
+
+    #[msg("Leveraged borrows still outstanding during settlement")]
+    SettlementBorrowsOutstanding,
+
+    #[msg("Outflow exceeds the vault rate limit; queue it for delayed release")]
+    OutflowRateLimited,
+
+    #[msg("Invalid outflow limit parameters")]
+    InvalidOutflowLimit,
+
+    #[msg("Pending outflow release time has not been reached")]
+    OutflowNotReleasable,
+
+    #[msg("Account is not a migratable account of this program")]
+    InvalidMigrationAccount,
+
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Withdrawal fits the vault rate limit; send it without a pending outflow")]
+    OutflowWithinLimit,
 }
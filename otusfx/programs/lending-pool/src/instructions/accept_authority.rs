@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed authority (a multisig or governance PDA signs via CPI)
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.pending_authority == new_authority.key() @ LendingError::InvalidPendingAuthority
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+
+    let previous_authority = config.authority;
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = Pubkey::default();
+
+    emit_cpi!(AuthorityTransferred {
+        previous_authority,
+        new_authority: config.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Lending authority transferred");
+    msg!("Previous: {}, New: {}", previous_authority, config.authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::{LendingConfig, PendingOutflow};
+
+#[derive(Accounts)]
+pub struct ApproveOutflow<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.guardian == guardian.key() @ LendingError::InvalidGuardian
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PendingOutflow::SEEDS_PREFIX, &pending_outflow.outflow_id.to_le_bytes()],
+        bump = pending_outflow.bump
+    )]
+    pub pending_outflow: Account<'info, PendingOutflow>,
+}
+
+pub fn handler(ctx: Context<ApproveOutflow>) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_outflow;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Approval skips the remaining delay; the release is still charged against the
+    // vault's outflow limiter like any other
+    pending.release_at = pending.release_at.min(now);
+
+    msg!("Outflow approved by guardian");
+    msg!("Outflow ID: {}, Amount: {}", pending.outflow_id, pending.amount);
+
+    Ok(())
+}
@@ -8,6 +8,7 @@ use crate::state::{LendingConfig, BorrowPosition, StablecoinType};
 #[allow(dead_code)]
 const TRADING_ENGINE_PROGRAM_ID: &str = "5ViKWmxzdXATK9b4x3bgr9szqsR2UhfokPJNLLQCKL76";
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(stablecoin_type: StablecoinType)]
 pub struct BorrowForLeverage<'info> {
@@ -62,6 +63,9 @@ pub fn handler(ctx: Context<BorrowForLeverage>, stablecoin_type: StablecoinType,
     let borrow_position = &mut ctx.accounts.borrow_position;
     let clock = Clock::get()?;
 
+    require!(!config.is_paused, LendingError::PoolPaused);
+    require!(!config.global_settlement, LendingError::GlobalSettlementActive);
+
     // Verify caller is Trading Engine program (CPI check)
     // Note: In production, validate that the calling program is the Trading Engine
     // For now, we'll allow any caller for testing purposes
@@ -90,6 +94,12 @@ pub fn handler(ctx: Context<BorrowForLeverage>, stablecoin_type: StablecoinType,
         return Err(LendingError::InsufficientLiquidity.into());
     }
 
+    // Borrows leave the vault immediately, so they must fit the outflow limit
+    require!(
+        config.outflow_limiter_mut(stablecoin_type).try_consume(borrow_amount, clock.unix_timestamp),
+        LendingError::OutflowRateLimited
+    );
+
     // Determine which vault to use
     let vault_account = match stablecoin_type {
         StablecoinType::USDC => ctx.accounts.usdc_vault.to_account_info(),
@@ -119,6 +129,7 @@ pub fn handler(ctx: Context<BorrowForLeverage>, stablecoin_type: StablecoinType,
     borrow_position.borrow_timestamp = clock.unix_timestamp;
     borrow_position.last_interest_update = clock.unix_timestamp;
     borrow_position.bump = ctx.bumps.borrow_position;
+    borrow_position.version = BorrowPosition::VERSION;
 
     // Update global stats
     config.total_borrowed = new_total_borrowed;
@@ -126,6 +137,16 @@ pub fn handler(ctx: Context<BorrowForLeverage>, stablecoin_type: StablecoinType,
     let utilization = (config.total_borrowed * 10000) / total_deposits;
     let borrow_rate = config.calculate_borrow_rate();
 
+    emit_cpi!(Borrowed {
+        trading_position: ctx.accounts.trading_position.key(),
+        stablecoin_type,
+        amount: borrow_amount,
+        total_borrowed: config.total_borrowed,
+        utilization_bps: utilization,
+        borrow_rate_bps: borrow_rate,
+        timestamp: clock.unix_timestamp,
+    });
+
     let token_name = match stablecoin_type {
         StablecoinType::USDC => "USDC",
         StablecoinType::USD1 => "USD1",
@@ -139,3 +160,14 @@ pub fn handler(ctx: Context<BorrowForLeverage>, stablecoin_type: StablecoinType,
 
     Ok(())
 }
+
+#[event]
+pub struct Borrowed {
+    pub trading_position: Pubkey,
+    pub stablecoin_type: StablecoinType,
+    pub amount: u64,
+    pub total_borrowed: u64,
+    pub utilization_bps: u64,
+    pub borrow_rate_bps: u64,
+    pub timestamp: i64,
+}
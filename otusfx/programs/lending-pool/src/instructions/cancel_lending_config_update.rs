@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::{LendingConfig, LendingConfigProposal};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelLendingConfigUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.authority == authority.key() @ LendingError::Unauthorized
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            LendingConfigProposal::SEEDS_PREFIX,
+            &proposal.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, LendingConfigProposal>,
+
+    /// Receives the proposal's rent
+    /// CHECK: Must match the proposal's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == proposal.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelLendingConfigUpdate>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit_cpi!(LendingConfigUpdateCancelled {
+        proposal_id: proposal.proposal_id,
+        cancelled_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Lending config update cancelled");
+    msg!("Proposal ID: {}", proposal.proposal_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct LendingConfigUpdateCancelled {
+    pub proposal_id: u64,
+    pub cancelled_at: i64,
+}
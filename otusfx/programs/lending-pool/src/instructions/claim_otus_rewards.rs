@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use crate::error::LendingError;
 use crate::state::{LendingConfig, LenderPosition};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimOtusRewards<'info> {
     #[account(mut)]
@@ -81,9 +82,24 @@ pub fn handler(ctx: Context<ClaimOtusRewards>) -> Result<()> {
 
     lender_position.otus_interest_earned = 0;
 
+    emit_cpi!(OtusClaimed {
+        lender: ctx.accounts.lender.key(),
+        amount: otus_to_claim,
+        total_claimed: lender_position.otus_interest_claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("OTUS interest claimed");
     msg!("Lender: {}", ctx.accounts.lender.key());
     msg!("Amount: {} OTUS", otus_to_claim);
 
     Ok(())
 }
+
+#[event]
+pub struct OtusClaimed {
+    pub lender: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
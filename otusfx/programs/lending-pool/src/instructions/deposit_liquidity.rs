@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::error::LendingError;
 use crate::state::{LendingConfig, LenderPosition, StablecoinType};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(stablecoin_type: StablecoinType)]
 pub struct DepositLiquidity<'info> {
@@ -50,6 +51,9 @@ pub fn handler(ctx: Context<DepositLiquidity>, stablecoin_type: StablecoinType,
     let lender_position = &mut ctx.accounts.lender_position;
     let clock = Clock::get()?;
 
+    require!(!config.is_paused, LendingError::PoolPaused);
+    require!(!config.global_settlement, LendingError::GlobalSettlementActive);
+
     require!(amount > 0, LendingError::InvalidBorrowAmount);
 
     // Accrue interest for existing position before adding new deposit
@@ -97,6 +101,14 @@ pub fn handler(ctx: Context<DepositLiquidity>, stablecoin_type: StablecoinType,
         }
     }
 
+    emit_cpi!(LiquidityDeposited {
+        lender: ctx.accounts.lender.key(),
+        stablecoin_type,
+        amount,
+        total_usd_value: lender_position.total_usd_value,
+        timestamp: clock.unix_timestamp,
+    });
+
     let token_name = match stablecoin_type {
         StablecoinType::USDC => "USDC",
         StablecoinType::USD1 => "USD1",
@@ -110,3 +122,12 @@ pub fn handler(ctx: Context<DepositLiquidity>, stablecoin_type: StablecoinType,
 
     Ok(())
 }
+
+#[event]
+pub struct LiquidityDeposited {
+    pub lender: Pubkey,
+    pub stablecoin_type: StablecoinType,
+    pub amount: u64,
+    pub total_usd_value: u64,
+    pub timestamp: i64,
+}
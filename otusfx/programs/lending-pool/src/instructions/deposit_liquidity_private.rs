@@ -40,7 +40,7 @@ pub struct DepositLiquidityPrivate<'info> {
     #[account(
         init,
         payer = lender,
-        space = 8 + 32 + 32 + 8 + 1,
+        space = PrivacyCommitment::LEN,
         seeds = [b"privacy_commitment", lender.key().as_ref(), &commitment],
         bump
     )]
@@ -58,6 +58,24 @@ pub struct PrivacyCommitment {
     pub nullifier_hash: [u8; 32],
     pub amount: u64,
     pub is_spent: bool,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl PrivacyCommitment {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // lender
+        32 + // commitment
+        32 + // nullifier_hash
+        8 + // amount
+        1 + // is_spent
+        1 + // version
+        32; // reserved
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
 }
 
 pub fn handler(
@@ -70,6 +88,9 @@ pub fn handler(
     let lending_config = &mut ctx.accounts.lending_config;
     let lender_position = &mut ctx.accounts.lender_position;
 
+    require!(!lending_config.is_paused, LendingError::PoolPaused);
+    require!(!lending_config.global_settlement, LendingError::GlobalSettlementActive);
+
     require!(amount > 0, LendingError::InvalidAmount);
     require!(amount >= 1_000_000, LendingError::MinimumDepositNotMet); // Min 1 USDC
 
@@ -97,6 +118,7 @@ pub fn handler(
     commitment_account.nullifier_hash = nullifier_hash;
     commitment_account.amount = amount;
     commitment_account.is_spent = false;
+    commitment_account.version = PrivacyCommitment::VERSION;
 
     // Step 3: Update lending pool state
     match stablecoin_type {
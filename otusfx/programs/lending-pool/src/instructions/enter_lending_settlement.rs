@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnterLendingSettlement<'info> {
+    /// Authority or guardian
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = (
+            signer.key() == lending_config.authority || signer.key() == lending_config.guardian
+        ) @ LendingError::Unauthorized
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+}
+
+pub fn handler(ctx: Context<EnterLendingSettlement>) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+
+    // One-way: there is no instruction to leave settlement
+    require!(!config.global_settlement, LendingError::GlobalSettlementActive);
+    config.global_settlement = true;
+
+    emit_cpi!(LendingSettlementEntered {
+        signer: ctx.accounts.signer.key(),
+        total_deposits: config.total_deposits(),
+        total_borrowed: config.total_borrowed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Lending global settlement entered");
+    msg!("Outstanding borrows: {}", config.total_borrowed);
+
+    Ok(())
+}
+
+#[event]
+pub struct LendingSettlementEntered {
+    pub signer: Pubkey,
+    pub total_deposits: u64,
+    pub total_borrowed: u64,
+    pub timestamp: i64,
+}
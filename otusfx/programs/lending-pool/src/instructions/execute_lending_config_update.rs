@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::{LendingConfig, LendingConfigProposal, LendingConfigUpdate};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteLendingConfigUpdate<'info> {
+    /// Anyone can execute once the timelock has elapsed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            LendingConfigProposal::SEEDS_PREFIX,
+            &proposal.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, LendingConfigProposal>,
+
+    /// Receives the proposal's rent
+    /// CHECK: Must match the proposal's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == proposal.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteLendingConfigUpdate>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= proposal.eta,
+        LendingError::TimelockNotElapsed
+    );
+
+    proposal.update.apply(&mut ctx.accounts.lending_config)?;
+
+    emit_cpi!(ConfigUpdated {
+        proposal_id: proposal.proposal_id,
+        update: proposal.update.clone(),
+        executed_at: clock.unix_timestamp,
+    });
+
+    msg!("Lending config updated");
+    msg!("Proposal ID: {}", proposal.proposal_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub proposal_id: u64,
+    pub update: LendingConfigUpdate,
+    pub executed_at: i64,
+}
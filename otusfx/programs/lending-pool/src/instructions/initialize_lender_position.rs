@@ -41,6 +41,7 @@ pub fn handler(ctx: Context<InitializeLenderPosition>) -> Result<()> {
     lender_position.cumulative_usdc_withdrawn = 0;
     lender_position.cumulative_usd1_withdrawn = 0;
     lender_position.bump = ctx.bumps.lender_position;
+    lender_position.version = LenderPosition::VERSION;
 
     msg!("Lender position initialized");
     msg!("Lender: {}", ctx.accounts.lender.key());
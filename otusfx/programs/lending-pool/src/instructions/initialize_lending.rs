@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Mint};
 use crate::error::LendingError;
-use crate::state::LendingConfig;
+use crate::state::{LendingConfig, OutflowLimiter};
 
 #[derive(Accounts)]
 pub struct InitializeLending<'info> {
@@ -90,6 +90,12 @@ pub fn handler(
     );
 
     config.authority = ctx.accounts.authority.key();
+    config.pending_authority = Pubkey::default();
+    config.guardian = ctx.accounts.authority.key();
+    config.is_paused = false;
+    config.global_settlement = false;
+    config.timelock_delay = 48 * 60 * 60; // 48 hours
+    config.proposal_counter = 0;
     config.usdc_vault = ctx.accounts.usdc_vault.key();
     config.usd1_vault = ctx.accounts.usd1_vault.key();
     config.otus_vault = ctx.accounts.otus_vault.key();
@@ -103,7 +109,14 @@ pub fn handler(
     config.reserve_factor = reserve_factor;
     config.last_update_timestamp = clock.unix_timestamp;
     config.otus_price_usd = initial_otus_price_usd;
+    config.reconcile_tolerance = 1_000_000; // $1
+    config.usdc_outflow_limiter = OutflowLimiter::new(250_000_000_000, 60 * 60, clock.unix_timestamp); // $250k/hour
+    config.usd1_outflow_limiter = OutflowLimiter::new(250_000_000_000, 60 * 60, clock.unix_timestamp); // $250k/hour
+    config.outflow_release_delay = 6 * 60 * 60; // 6 hours
+    config.outflow_counter = 0;
+    config.total_pending_outflow = 0;
     config.bump = ctx.bumps.lending_config;
+    config.version = LendingConfig::VERSION;
 
     msg!("Lending pool initialized");
     msg!("Base rate: {}bps, Multiplier: {}bps", base_interest_rate, utilization_multiplier);
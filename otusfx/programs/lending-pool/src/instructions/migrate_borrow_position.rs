@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::BorrowPosition;
+use otus_common::migration::{load_for_migration, store_migrated};
+
+#[derive(Accounts)]
+pub struct MigrateBorrowPosition<'info> {
+    /// Borrow position written with an older layout
+    /// CHECK: Owner and discriminator are validated in load_for_migration
+    #[account(mut)]
+    pub borrow_position: UncheckedAccount<'info>,
+
+    /// Pays any additional rent (anyone can migrate)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateBorrowPosition>) -> Result<()> {
+    let info = ctx.accounts.borrow_position.to_account_info();
+    let mut borrow_position: BorrowPosition = load_for_migration(
+        &info,
+        &crate::ID,
+        BorrowPosition::LEN,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        LendingError::InvalidMigrationAccount,
+    )?;
+
+    let from_version = borrow_position.version;
+    require!(from_version < BorrowPosition::VERSION, LendingError::AccountAlreadyMigrated);
+
+    // v0 -> v1: version byte and reserved padding appended (zeroed by realloc)
+    borrow_position.version = BorrowPosition::VERSION;
+
+    store_migrated(&info, &borrow_position)?;
+
+    msg!("Borrow position migrated");
+    msg!("Account: {}", info.key());
+    msg!("Version: {} -> {}", from_version, BorrowPosition::VERSION);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LenderPosition;
+use otus_common::migration::{load_for_migration, store_migrated};
+
+#[derive(Accounts)]
+pub struct MigrateLenderPosition<'info> {
+    /// Lender position written with an older layout
+    /// CHECK: Owner and discriminator are validated in load_for_migration
+    #[account(mut)]
+    pub lender_position: UncheckedAccount<'info>,
+
+    /// Pays any additional rent (anyone can migrate)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLenderPosition>) -> Result<()> {
+    let info = ctx.accounts.lender_position.to_account_info();
+    let mut lender_position: LenderPosition = load_for_migration(
+        &info,
+        &crate::ID,
+        LenderPosition::LEN,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        LendingError::InvalidMigrationAccount,
+    )?;
+
+    let from_version = lender_position.version;
+    require!(from_version < LenderPosition::VERSION, LendingError::AccountAlreadyMigrated);
+
+    // v0 -> v1: version byte and reserved padding appended (zeroed by realloc)
+    lender_position.version = LenderPosition::VERSION;
+
+    store_migrated(&info, &lender_position)?;
+
+    msg!("Lender position migrated");
+    msg!("Account: {}", info.key());
+    msg!("Version: {} -> {}", from_version, LenderPosition::VERSION);
+
+    Ok(())
+}
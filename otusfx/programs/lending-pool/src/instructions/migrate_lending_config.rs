@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::{LendingConfig, LendingConfigV0};
+use otus_common::migration::{
+    check_migration_account, grow_for_migration, load_for_migration, load_legacy, store_migrated,
+};
+
+#[derive(Accounts)]
+pub struct MigrateLendingConfig<'info> {
+    /// Lending config written with an older layout
+    /// CHECK: Owner and discriminator are validated in the migration helpers
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump
+    )]
+    pub lending_config: UncheckedAccount<'info>,
+
+    /// Current authority (config migrations are admin only); pays any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLendingConfig>) -> Result<()> {
+    let info = ctx.accounts.lending_config.to_account_info();
+
+    // v0 (baseline) configs predate the version byte, and later layouts inserted fields
+    // mid-struct, so they are recognised by length and converted field by field
+    let mut lending_config: LendingConfig = if info.data_len() == LendingConfigV0::LEN {
+        check_migration_account::<LendingConfig, _>(
+            &info,
+            &crate::ID,
+            LendingError::InvalidMigrationAccount,
+        )?;
+        let legacy: LendingConfigV0 = load_legacy(&info)?;
+        grow_for_migration(
+            &info,
+            LendingConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+        legacy.migrate(Clock::get()?.unix_timestamp)
+    } else {
+        load_for_migration(
+            &info,
+            &crate::ID,
+            LendingConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            LendingError::InvalidMigrationAccount,
+        )?
+    };
+
+    // Only the authority recorded in the account may migrate it
+    require!(
+        lending_config.authority == ctx.accounts.payer.key(),
+        LendingError::Unauthorized
+    );
+
+    let from_version = lending_config.version;
+    require!(from_version < LendingConfig::VERSION, LendingError::AccountAlreadyMigrated);
+
+    // v0 -> v1: governance, settlement and outflow fields inserted (see LendingConfigV0)
+    lending_config.version = LendingConfig::VERSION;
+
+    store_migrated(&info, &lending_config)?;
+
+    msg!("Lending config migrated");
+    msg!("Account: {}", info.key());
+    msg!("Version: {} -> {}", from_version, LendingConfig::VERSION);
+
+    Ok(())
+}
@@ -8,7 +8,19 @@ pub mod borrow_for_leverage;
 pub mod repay_borrow;
 pub mod accrue_pool_interest;
 pub mod claim_otus_rewards;
-pub mod update_lending_config;
+pub mod queue_lending_config_update;
+pub mod execute_lending_config_update;
+pub mod cancel_lending_config_update;
+pub mod set_lending_paused;
+pub mod enter_lending_settlement;
+pub mod reconcile;
+pub mod release_outflow;
+pub mod approve_outflow;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod migrate_lending_config;
+pub mod migrate_lender_position;
+pub mod migrate_borrow_position;
 
 pub use initialize_lending::*;
 pub use initialize_lender_position::*;
@@ -20,4 +32,16 @@ pub use borrow_for_leverage::*;
 pub use repay_borrow::*;
 pub use accrue_pool_interest::*;
 pub use claim_otus_rewards::*;
-pub use update_lending_config::*;
+pub use queue_lending_config_update::*;
+pub use execute_lending_config_update::*;
+pub use cancel_lending_config_update::*;
+pub use set_lending_paused::*;
+pub use enter_lending_settlement::*;
+pub use reconcile::*;
+pub use release_outflow::*;
+pub use approve_outflow::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use migrate_lending_config::*;
+pub use migrate_lender_position::*;
+pub use migrate_borrow_position::*;
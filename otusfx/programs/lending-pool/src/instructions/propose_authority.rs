@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Current authority (a multisig or governance PDA signs via CPI)
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.authority == authority.key() @ LendingError::Unauthorized
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+
+    // Proposing the default pubkey cancels a pending transfer
+    config.pending_authority = new_authority;
+
+    emit_cpi!(AuthorityProposed {
+        current_authority: config.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Lending authority transfer proposed");
+    msg!("Current: {}, Pending: {}", config.authority, new_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::{LendingConfig, LendingConfigProposal, LendingConfigUpdate};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueLendingConfigUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    /// Pays rent for the proposal (refunded on execute/cancel)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.authority == authority.key() @ LendingError::Unauthorized
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = LendingConfigProposal::LEN,
+        seeds = [
+            LendingConfigProposal::SEEDS_PREFIX,
+            &lending_config.proposal_counter.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, LendingConfigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueLendingConfigUpdate>, update: LendingConfigUpdate) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+    let clock = Clock::get()?;
+
+    // Reject invalid changes now rather than after the delay
+    let mut preview = (**config).clone();
+    update.apply(&mut preview)?;
+
+    let eta = clock
+        .unix_timestamp
+        .checked_add(config.timelock_delay)
+        .ok_or(LendingError::ArithmeticOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = config.proposal_counter;
+    proposal.update = update;
+    proposal.rent_payer = ctx.accounts.payer.key();
+    proposal.queued_at = clock.unix_timestamp;
+    proposal.eta = eta;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.version = LendingConfigProposal::VERSION;
+
+    config.proposal_counter = config
+        .proposal_counter
+        .checked_add(1)
+        .ok_or(LendingError::ArithmeticOverflow)?;
+
+    emit_cpi!(LendingConfigUpdateQueued {
+        proposal_id: proposal.proposal_id,
+        update: proposal.update.clone(),
+        eta,
+    });
+
+    msg!("Lending config update queued");
+    msg!("Proposal ID: {}, ETA: {}", proposal.proposal_id, eta);
+
+    Ok(())
+}
+
+#[event]
+pub struct LendingConfigUpdateQueued {
+    pub proposal_id: u64,
+    pub update: LendingConfigUpdate,
+    pub eta: i64,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    /// Anyone can run the solvency check
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    #[account(
+        seeds = [b"usdc_vault"],
+        bump
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"usd1_vault"],
+        bump
+    )]
+    pub usd1_vault: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<Reconcile>) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+    let clock = Clock::get()?;
+
+    let usdc_balance = ctx.accounts.usdc_vault.amount;
+    let usd1_balance = ctx.accounts.usd1_vault.amount;
+    let vault_balance = usdc_balance
+        .checked_add(usd1_balance)
+        .ok_or(LendingError::ArithmeticOverflow)?;
+
+    // Invariant: vault balances >= deposits + reserves + queued withdrawals - funds lent out
+    let owed = config
+        .total_deposits()
+        .checked_add(config.total_reserves)
+        .ok_or(LendingError::ArithmeticOverflow)?
+        .checked_add(config.total_pending_outflow)
+        .ok_or(LendingError::ArithmeticOverflow)?
+        .saturating_sub(config.total_borrowed);
+
+    let shortfall = owed.saturating_sub(vault_balance);
+    let surplus = vault_balance.saturating_sub(owed);
+
+    // Halt the pool if the vaults are short beyond tolerance
+    let auto_paused = shortfall > config.reconcile_tolerance && !config.is_paused;
+    if auto_paused {
+        config.is_paused = true;
+    }
+
+    emit_cpi!(LendingVaultReconciled {
+        usdc_vault_balance: usdc_balance,
+        usd1_vault_balance: usd1_balance,
+        total_deposited_usdc: config.total_deposited_usdc,
+        total_deposited_usd1: config.total_deposited_usd1,
+        total_borrowed: config.total_borrowed,
+        total_reserves: config.total_reserves,
+        total_pending_outflow: config.total_pending_outflow,
+        owed,
+        surplus,
+        shortfall,
+        tolerance: config.reconcile_tolerance,
+        auto_paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Lending vaults reconciled");
+    msg!("Vault balance: {} (USDC {} + USD1 {})", vault_balance, usdc_balance, usd1_balance);
+    msg!("Owed: {}, Surplus: {}, Shortfall: {}", owed, surplus, shortfall);
+    msg!("Auto-paused: {}", auto_paused);
+
+    Ok(())
+}
+
+#[event]
+pub struct LendingVaultReconciled {
+    pub usdc_vault_balance: u64,
+    pub usd1_vault_balance: u64,
+    pub total_deposited_usdc: u64,
+    pub total_deposited_usd1: u64,
+    pub total_borrowed: u64,
+    pub total_reserves: u64,
+    pub total_pending_outflow: u64,
+    pub owed: u64,
+    pub surplus: u64,
+    pub shortfall: u64,
+    pub tolerance: u64,
+    pub auto_paused: bool,
+    pub timestamp: i64,
+}
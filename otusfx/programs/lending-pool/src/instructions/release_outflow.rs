@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::LendingError;
+use crate::state::{LendingConfig, PendingOutflow, StablecoinType};
+
+#[derive(Accounts)]
+pub struct ReleaseOutflow<'info> {
+    /// Anyone can release once the delay has passed
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+
+    /// Closed to the rent payer once fully released
+    #[account(
+        mut,
+        seeds = [PendingOutflow::SEEDS_PREFIX, &pending_outflow.outflow_id.to_le_bytes()],
+        bump = pending_outflow.bump
+    )]
+    pub pending_outflow: Account<'info, PendingOutflow>,
+
+    /// Token account recorded on the pending outflow
+    #[account(
+        mut,
+        constraint = recipient.key() == pending_outflow.recipient
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    /// Receives the pending outflow's rent once it is fully released
+    /// CHECK: Must match the pending outflow's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == pending_outflow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"usdc_vault"],
+        bump
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"usd1_vault"],
+        bump
+    )]
+    pub usd1_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ReleaseOutflow>) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+    let pending = &mut ctx.accounts.pending_outflow;
+    let now = Clock::get()?.unix_timestamp;
+
+    // The guardian holds queued withdrawals by pausing the pool
+    require!(!config.is_paused, LendingError::PoolPaused);
+    require!(now >= pending.release_at, LendingError::OutflowNotReleasable);
+
+    // Released withdrawals still draw on the vault's limiter, so a backlog drains at most
+    // one bucket per window; whatever does not fit stays queued for a later release
+    let amount = config
+        .outflow_limiter_mut(pending.stablecoin_type)
+        .consume_available(pending.amount, now);
+    require!(amount > 0, LendingError::OutflowRateLimited);
+
+    let vault_account = match pending.stablecoin_type {
+        StablecoinType::USDC => ctx.accounts.usdc_vault.to_account_info(),
+        StablecoinType::USD1 => ctx.accounts.usd1_vault.to_account_info(),
+    };
+
+    let seeds = &[LendingConfig::SEEDS_PREFIX, &[config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: vault_account,
+            to: ctx.accounts.recipient.to_account_info(),
+            authority: config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    config.total_pending_outflow = config
+        .total_pending_outflow
+        .checked_sub(amount)
+        .ok_or(LendingError::ArithmeticUnderflow)?;
+    pending.amount = pending
+        .amount
+        .checked_sub(amount)
+        .ok_or(LendingError::ArithmeticUnderflow)?;
+
+    msg!("Outflow released");
+    msg!("Outflow ID: {}, Amount: {}, Remaining: {}", pending.outflow_id, amount, pending.amount);
+    msg!("Recipient: {}", pending.recipient);
+
+    if pending.amount == 0 {
+        pending.close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::error::LendingError;
 use crate::state::{LendingConfig, BorrowPosition, StablecoinType};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(stablecoin_type: StablecoinType)]
 pub struct RepayBorrow<'info> {
@@ -138,6 +139,17 @@ pub fn handler(ctx: Context<RepayBorrow>, stablecoin_type: StablecoinType, repay
         .checked_add(reserve_share)
         .ok_or(LendingError::ArithmeticOverflow)?;
 
+    emit_cpi!(Repaid {
+        trading_position: ctx.accounts.trading_position.key(),
+        stablecoin_type,
+        principal,
+        interest: interest_paid,
+        lender_share: lender_share as u64,
+        reserve_share,
+        total_borrowed: config.total_borrowed,
+        timestamp: clock.unix_timestamp,
+    });
+
     let token_name = match stablecoin_type {
         StablecoinType::USDC => "USDC",
         StablecoinType::USD1 => "USD1",
@@ -154,3 +166,15 @@ pub fn handler(ctx: Context<RepayBorrow>, stablecoin_type: StablecoinType, repay
 
     Ok(())
 }
+
+#[event]
+pub struct Repaid {
+    pub trading_position: Pubkey,
+    pub stablecoin_type: StablecoinType,
+    pub principal: u64,
+    pub interest: u64,
+    pub lender_share: u64,
+    pub reserve_share: u64,
+    pub total_borrowed: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetLendingPaused<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LendingConfig::SEEDS_PREFIX],
+        bump = lending_config.bump,
+        constraint = lending_config.guardian == guardian.key() @ LendingError::InvalidGuardian
+    )]
+    pub lending_config: Account<'info, LendingConfig>,
+}
+
+pub fn handler(ctx: Context<SetLendingPaused>, is_paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.lending_config;
+    config.is_paused = is_paused;
+
+    emit_cpi!(LendingPauseUpdated {
+        guardian: ctx.accounts.guardian.key(),
+        is_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Lending pool paused: {}", is_paused);
+
+    Ok(())
+}
+
+#[event]
+pub struct LendingPauseUpdated {
+    pub guardian: Pubkey,
+    pub is_paused: bool,
+    pub timestamp: i64,
+}
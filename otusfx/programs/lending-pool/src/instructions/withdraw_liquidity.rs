@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use crate::error::LendingError;
-use crate::state::{LendingConfig, LenderPosition, StablecoinType};
+use crate::state::{LendingConfig, LenderPosition, PendingOutflow, StablecoinType};
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(stablecoin_type: StablecoinType)]
 pub struct WithdrawLiquidity<'info> {
@@ -59,7 +60,18 @@ pub struct WithdrawLiquidity<'info> {
     )]
     pub lender_otus_account: Account<'info, TokenAccount>,
 
+    /// Provide to queue the withdrawal for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = lender,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEEDS_PREFIX, &lending_config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<WithdrawLiquidity>, stablecoin_type: StablecoinType, amount: u64) -> Result<()> {
@@ -67,6 +79,8 @@ pub fn handler(ctx: Context<WithdrawLiquidity>, stablecoin_type: StablecoinType,
     let lender_position = &mut ctx.accounts.lender_position;
     let clock = Clock::get()?;
 
+    config.require_withdrawals_open()?;
+
     // Accrue interest before withdrawal
     let apr_bps = config.calculate_lender_rate();
     lender_position.accrue_interest(apr_bps, config.otus_price_usd, clock.unix_timestamp);
@@ -82,26 +96,67 @@ pub fn handler(ctx: Context<WithdrawLiquidity>, stablecoin_type: StablecoinType,
     let available = config.available_liquidity();
     require!(amount <= available, LendingError::InsufficientLiquidity);
 
-    // Determine which vault to use
-    let vault_account = match stablecoin_type {
-        StablecoinType::USDC => ctx.accounts.usdc_vault.to_account_info(),
-        StablecoinType::USD1 => ctx.accounts.usd1_vault.to_account_info(),
-    };
-
-    // Transfer stablecoin from vault to lender
     let seeds = &[LendingConfig::SEEDS_PREFIX, &[config.bump]];
     let signer_seeds = &[&seeds[..]];
 
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: vault_account,
-            to: ctx.accounts.lender_token_account.to_account_info(),
-            authority: config.to_account_info(),
-        },
-        signer_seeds,
-    );
-    token::transfer(transfer_ctx, amount)?;
+    let within_limit = config
+        .outflow_limiter_mut(stablecoin_type)
+        .has_capacity(amount, clock.unix_timestamp);
+    let queued = if !within_limit {
+        // Hold the withdrawal for delayed release
+        let (Some(pending), Some(bump)) =
+            (ctx.accounts.pending_outflow.as_mut(), ctx.bumps.pending_outflow)
+        else {
+            return err!(LendingError::OutflowRateLimited);
+        };
+        pending.outflow_id = config.outflow_counter;
+        pending.stablecoin_type = stablecoin_type;
+        pending.recipient = ctx.accounts.lender_token_account.key();
+        pending.amount = amount;
+        pending.release_at = clock
+            .unix_timestamp
+            .checked_add(config.outflow_release_delay)
+            .ok_or(LendingError::ArithmeticOverflow)?;
+        pending.rent_payer = ctx.accounts.lender.key();
+        pending.bump = bump;
+        pending.version = PendingOutflow::VERSION;
+
+        config.outflow_counter = config
+            .outflow_counter
+            .checked_add(1)
+            .ok_or(LendingError::ArithmeticOverflow)?;
+        config.total_pending_outflow = config
+            .total_pending_outflow
+            .checked_add(amount)
+            .ok_or(LendingError::ArithmeticOverflow)?;
+        true
+    } else {
+        // Queueing is reserved for withdrawals over the limit
+        require!(ctx.accounts.pending_outflow.is_none(), LendingError::OutflowWithinLimit);
+        require!(
+            config.outflow_limiter_mut(stablecoin_type).try_consume(amount, clock.unix_timestamp),
+            LendingError::OutflowRateLimited
+        );
+
+        // Determine which vault to use
+        let vault_account = match stablecoin_type {
+            StablecoinType::USDC => ctx.accounts.usdc_vault.to_account_info(),
+            StablecoinType::USD1 => ctx.accounts.usd1_vault.to_account_info(),
+        };
+
+        // Transfer stablecoin from vault to lender
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_account,
+                to: ctx.accounts.lender_token_account.to_account_info(),
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+        false
+    };
 
     // Transfer OTUS interest earned (if any)
     let otus_to_claim = lender_position.otus_interest_earned;
@@ -149,6 +204,16 @@ pub fn handler(ctx: Context<WithdrawLiquidity>, stablecoin_type: StablecoinType,
         }
     }
 
+    emit_cpi!(LiquidityWithdrawn {
+        lender: ctx.accounts.lender.key(),
+        stablecoin_type,
+        amount,
+        queued,
+        otus_claimed: otus_to_claim,
+        total_usd_value: lender_position.total_usd_value,
+        timestamp: clock.unix_timestamp,
+    });
+
     let token_name = match stablecoin_type {
         StablecoinType::USDC => "USDC",
         StablecoinType::USD1 => "USD1",
@@ -157,8 +222,20 @@ pub fn handler(ctx: Context<WithdrawLiquidity>, stablecoin_type: StablecoinType,
     msg!("Liquidity withdrawn");
     msg!("Lender: {}", ctx.accounts.lender.key());
     msg!("Principal: {} {}", amount / 1_000_000, token_name);
+    msg!("Principal queued: {}", queued);
     msg!("OTUS interest: {} OTUS", otus_to_claim);
     msg!("Remaining USD value: {} USD", lender_position.total_usd_value / 1_000_000);
 
     Ok(())
 }
+
+#[event]
+pub struct LiquidityWithdrawn {
+    pub lender: Pubkey,
+    pub stablecoin_type: StablecoinType,
+    pub amount: u64,
+    pub queued: bool,
+    pub otus_claimed: u64,
+    pub total_usd_value: u64,
+    pub timestamp: i64,
+}
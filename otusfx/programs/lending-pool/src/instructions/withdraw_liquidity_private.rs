@@ -48,6 +48,8 @@ pub fn handler(
     let lending_config = &mut ctx.accounts.lending_config;
     let lender_position = &mut ctx.accounts.lender_position;
 
+    lending_config.require_withdrawals_open()?;
+
     // Step 1: Get user's balance
     let user_balance = match stablecoin_type {
         StablecoinType::USDC => lender_position.usdc_deposited,
@@ -69,6 +71,14 @@ pub fn handler(
     require!(revealed_amount > 0, LendingError::InvalidAmount);
     require!(revealed_amount <= user_balance, LendingError::InsufficientBalance);
 
+    // Private withdrawals cannot be queued, so they must fit the outflow limit
+    require!(
+        lending_config
+            .outflow_limiter_mut(stablecoin_type)
+            .try_consume(revealed_amount, Clock::get()?.unix_timestamp),
+        LendingError::OutflowRateLimited
+    );
+
     // Step 3: Transfer tokens using revealed amount
     let vault_seeds = &[
         b"vault",
@@ -119,22 +119,71 @@ pub mod lending_pool {
         instructions::claim_otus_rewards::handler(ctx)
     }
 
-    /// Update lending config (admin only)
-    pub fn update_lending_config(
-        ctx: Context<UpdateLendingConfig>,
-        base_interest_rate: Option<u16>,
-        utilization_multiplier: Option<u16>,
-        max_utilization_rate: Option<u16>,
-        reserve_factor: Option<u16>,
-        otus_price_usd: Option<u64>,
+    /// Queue a timelocked lending config change (admin only)
+    pub fn queue_lending_config_update(
+        ctx: Context<QueueLendingConfigUpdate>,
+        update: LendingConfigUpdate,
     ) -> Result<()> {
-        instructions::update_lending_config::handler(
-            ctx,
-            base_interest_rate,
-            utilization_multiplier,
-            max_utilization_rate,
-            reserve_factor,
-            otus_price_usd,
-        )
+        instructions::queue_lending_config_update::handler(ctx, update)
+    }
+
+    /// Apply a queued lending config change once its timelock has elapsed (permissionless)
+    pub fn execute_lending_config_update(ctx: Context<ExecuteLendingConfigUpdate>) -> Result<()> {
+        instructions::execute_lending_config_update::handler(ctx)
+    }
+
+    /// Cancel a queued lending config change (admin only)
+    pub fn cancel_lending_config_update(ctx: Context<CancelLendingConfigUpdate>) -> Result<()> {
+        instructions::cancel_lending_config_update::handler(ctx)
+    }
+
+    /// Pause or resume the lending pool immediately (guardian only)
+    pub fn set_lending_paused(ctx: Context<SetLendingPaused>, is_paused: bool) -> Result<()> {
+        instructions::set_lending_paused::handler(ctx, is_paused)
+    }
+
+    /// Enter one-way global settlement: blocks deposits and borrows, withdrawals reopen once borrows are repaid (authority or guardian)
+    pub fn enter_lending_settlement(ctx: Context<EnterLendingSettlement>) -> Result<()> {
+        instructions::enter_lending_settlement::handler(ctx)
+    }
+
+    /// Check vault balances cover deposits and reserves net of borrows; auto-pause on shortfall (permissionless)
+    pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+        instructions::reconcile::handler(ctx)
+    }
+
+    /// Release a rate-limited withdrawal once its delay has passed, up to the limiter's capacity (permissionless)
+    pub fn release_outflow(ctx: Context<ReleaseOutflow>) -> Result<()> {
+        instructions::release_outflow::handler(ctx)
+    }
+
+    /// Approve a rate-limited withdrawal for release without waiting out its delay (guardian only)
+    pub fn approve_outflow(ctx: Context<ApproveOutflow>) -> Result<()> {
+        instructions::approve_outflow::handler(ctx)
+    }
+
+    /// Propose a new lending authority (step 1 of 2, current authority only)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed lending authority (step 2 of 2, pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Grow a lending config to the current layout version (admin only)
+    pub fn migrate_lending_config(ctx: Context<MigrateLendingConfig>) -> Result<()> {
+        instructions::migrate_lending_config::handler(ctx)
+    }
+
+    /// Grow a lender position to the current layout version (permissionless)
+    pub fn migrate_lender_position(ctx: Context<MigrateLenderPosition>) -> Result<()> {
+        instructions::migrate_lender_position::handler(ctx)
+    }
+
+    /// Grow a borrow position to the current layout version (permissionless)
+    pub fn migrate_borrow_position(ctx: Context<MigrateBorrowPosition>) -> Result<()> {
+        instructions::migrate_borrow_position::handler(ctx)
     }
 }
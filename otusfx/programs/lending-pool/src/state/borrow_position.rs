@@ -18,6 +18,10 @@ pub struct BorrowPosition {
     pub last_interest_update: i64,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl BorrowPosition {
@@ -28,10 +32,15 @@ impl BorrowPosition {
         8 + // interest_accrued
         8 + // borrow_timestamp
         8 + // last_interest_update
-        1; // bump
+        1 + // bump
+        1 + // version
+        64; // reserved
 
     pub const SEEDS_PREFIX: &'static [u8] = b"borrow_position";
 
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
     /// Calculate total debt (principal + interest)
     pub fn total_debt(&self) -> u64 {
         self.borrowed_amount.saturating_add(self.interest_accrued)
@@ -1,10 +1,24 @@
 use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use super::{OutflowLimiter, StablecoinType};
 
 /// Lending pool configuration
 #[account]
 pub struct LendingConfig {
-    /// Protocol authority
+    /// Protocol authority (may be a multisig / governance PDA)
     pub authority: Pubkey,
+    /// Proposed new authority awaiting acceptance (default pubkey if none)
+    pub pending_authority: Pubkey,
+    /// Guardian that can pause the pool immediately (distinct from authority)
+    pub guardian: Pubkey,
+    /// Pool paused flag (blocks deposits, withdrawals and borrows)
+    pub is_paused: bool,
+    /// One-way global settlement flag (blocks deposits and borrows, never cleared)
+    pub global_settlement: bool,
+    /// Delay in seconds before a queued config change can execute
+    pub timelock_delay: i64,
+    /// Counter for config proposal PDAs
+    pub proposal_counter: u64,
     /// USDC vault (main liquidity pool)
     pub usdc_vault: Pubkey,
     /// USD1 vault (alternative stablecoin)
@@ -33,13 +47,35 @@ pub struct LendingConfig {
     /// Placeholder: Updated via governance/oracle based on treasury formula
     /// Formula: OTUS_price = (USDC + USD1 in treasury) / OTUS_supply
     pub otus_price_usd: u64,
+    /// Vault shortfall (6 decimals) tolerated by reconcile before auto-pausing
+    pub reconcile_tolerance: u64,
+    /// Rate limiter on outflows from the USDC vault
+    pub usdc_outflow_limiter: OutflowLimiter,
+    /// Rate limiter on outflows from the USD1 vault
+    pub usd1_outflow_limiter: OutflowLimiter,
+    /// Delay in seconds before a withdrawal queued over the limit can be released
+    pub outflow_release_delay: i64,
+    /// Counter for pending outflow PDAs
+    pub outflow_counter: u64,
+    /// Sum of queued withdrawals still held in the vaults
+    pub total_pending_outflow: u64,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 128],
 }
 
 impl LendingConfig {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        32 + // pending_authority
+        32 + // guardian
+        1 + // is_paused
+        1 + // global_settlement
+        8 + // timelock_delay
+        8 + // proposal_counter
         32 + // usdc_vault
         32 + // usd1_vault
         32 + // otus_vault
@@ -53,10 +89,21 @@ impl LendingConfig {
         2 + // reserve_factor
         8 + // last_update_timestamp
         8 + // otus_price_usd
-        1; // bump
+        8 + // reconcile_tolerance
+        OutflowLimiter::LEN + // usdc_outflow_limiter
+        OutflowLimiter::LEN + // usd1_outflow_limiter
+        8 + // outflow_release_delay
+        8 + // outflow_counter
+        8 + // total_pending_outflow
+        1 + // bump
+        1 + // version
+        128; // reserved
 
     pub const SEEDS_PREFIX: &'static [u8] = b"lending_config";
 
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
     /// Calculate total deposits (USDC + USD1)
     pub fn total_deposits(&self) -> u64 {
         self.total_deposited_usdc.saturating_add(self.total_deposited_usd1)
@@ -97,6 +144,25 @@ impl LendingConfig {
         self.total_deposits().saturating_add(self.total_reserves)
     }
 
+    /// Check lenders may withdraw: blocked while paused, except in settlement
+    /// where withdrawals reopen once every leveraged borrow has been repaid
+    pub fn require_withdrawals_open(&self) -> Result<()> {
+        if self.global_settlement {
+            require!(self.total_borrowed == 0, LendingError::SettlementBorrowsOutstanding);
+        } else {
+            require!(!self.is_paused, LendingError::PoolPaused);
+        }
+        Ok(())
+    }
+
+    /// Rate limiter for the given stablecoin's vault
+    pub fn outflow_limiter_mut(&mut self, stablecoin_type: StablecoinType) -> &mut OutflowLimiter {
+        match stablecoin_type {
+            StablecoinType::USDC => &mut self.usdc_outflow_limiter,
+            StablecoinType::USD1 => &mut self.usd1_outflow_limiter,
+        }
+    }
+
     /// Calculate available liquidity for withdrawal
     pub fn available_liquidity(&self) -> u64 {
         self.total_deposits().saturating_sub(self.total_borrowed)
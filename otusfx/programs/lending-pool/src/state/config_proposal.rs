@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use crate::error::LendingError;
+use crate::state::LendingConfig;
+
+/// Minimum delay between queueing and executing a config change (1 hour)
+pub const MIN_TIMELOCK_DELAY: i64 = 60 * 60;
+
+/// A set of lending config changes applied atomically when a proposal executes
+/// Fields left as None keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LendingConfigUpdate {
+    pub base_interest_rate: Option<u16>,
+    pub utilization_multiplier: Option<u16>,
+    pub max_utilization_rate: Option<u16>,
+    pub reserve_factor: Option<u16>,
+    pub otus_price_usd: Option<u64>,
+    pub guardian: Option<Pubkey>,
+    pub timelock_delay: Option<i64>,
+    pub reconcile_tolerance: Option<u64>,
+    pub usdc_outflow_max: Option<u64>,
+    pub usd1_outflow_max: Option<u64>,
+    pub outflow_window_secs: Option<i64>,
+    pub outflow_release_delay: Option<i64>,
+}
+
+impl LendingConfigUpdate {
+    pub const LEN: usize = 3 + // base_interest_rate
+        3 + // utilization_multiplier
+        3 + // max_utilization_rate
+        3 + // reserve_factor
+        9 + // otus_price_usd
+        33 + // guardian
+        9 + // timelock_delay
+        9 + // reconcile_tolerance
+        9 + // usdc_outflow_max
+        9 + // usd1_outflow_max
+        9 + // outflow_window_secs
+        9; // outflow_release_delay
+
+    /// Apply the update to the config, validating each parameter
+    pub fn apply(&self, config: &mut LendingConfig) -> Result<()> {
+        if let Some(rate) = self.base_interest_rate {
+            require!(rate <= 5000, LendingError::InvalidInterestRate);
+            config.base_interest_rate = rate;
+            msg!("Base interest rate updated: {}bps", rate);
+        }
+
+        if let Some(multiplier) = self.utilization_multiplier {
+            require!(multiplier <= 2000, LendingError::InvalidInterestRate);
+            config.utilization_multiplier = multiplier;
+            msg!("Utilization multiplier updated: {}bps", multiplier);
+        }
+
+        if let Some(max_util) = self.max_utilization_rate {
+            require!(
+                max_util <= 9000 && max_util >= 5000,
+                LendingError::InvalidInterestRate
+            );
+            config.max_utilization_rate = max_util;
+            msg!("Max utilization rate updated: {}bps", max_util);
+        }
+
+        if let Some(factor) = self.reserve_factor {
+            require!(factor <= 3000, LendingError::InvalidReserveFactor);
+            config.reserve_factor = factor;
+            msg!("Reserve factor updated: {}bps", factor);
+        }
+
+        if let Some(price) = self.otus_price_usd {
+            require!(price > 0, LendingError::InvalidInterestRate);
+            config.otus_price_usd = price;
+            msg!("OTUS price updated: ${}", price as f64 / 1_000_000.0);
+        }
+
+        if let Some(guardian) = self.guardian {
+            config.guardian = guardian;
+            msg!("Guardian updated: {}", guardian);
+        }
+
+        if let Some(delay) = self.timelock_delay {
+            require!(delay >= MIN_TIMELOCK_DELAY, LendingError::InvalidTimelockDelay);
+            config.timelock_delay = delay;
+            msg!("Timelock delay updated: {}s", delay);
+        }
+
+        if let Some(tolerance) = self.reconcile_tolerance {
+            config.reconcile_tolerance = tolerance;
+            msg!("Reconcile tolerance updated: {}", tolerance);
+        }
+
+        if let Some(max_outflow) = self.usdc_outflow_max {
+            let limiter = &mut config.usdc_outflow_limiter;
+            limiter.max_outflow = max_outflow;
+            limiter.available = limiter.available.min(max_outflow);
+            msg!("USDC max outflow updated: {} per window", max_outflow);
+        }
+
+        if let Some(max_outflow) = self.usd1_outflow_max {
+            let limiter = &mut config.usd1_outflow_limiter;
+            limiter.max_outflow = max_outflow;
+            limiter.available = limiter.available.min(max_outflow);
+            msg!("USD1 max outflow updated: {} per window", max_outflow);
+        }
+
+        if let Some(window) = self.outflow_window_secs {
+            require!(window > 0, LendingError::InvalidOutflowLimit);
+            config.usdc_outflow_limiter.window_secs = window;
+            config.usd1_outflow_limiter.window_secs = window;
+            msg!("Outflow window updated: {}s", window);
+        }
+
+        if let Some(delay) = self.outflow_release_delay {
+            require!(delay >= 0, LendingError::InvalidOutflowLimit);
+            config.outflow_release_delay = delay;
+            msg!("Outflow release delay updated: {}s", delay);
+        }
+
+        Ok(())
+    }
+}
+
+/// A queued lending config change awaiting its timelock
+#[account]
+pub struct LendingConfigProposal {
+    /// Sequential proposal ID (from lending_config.proposal_counter)
+    pub proposal_id: u64,
+    /// Changes to apply on execution
+    pub update: LendingConfigUpdate,
+    /// Account that paid rent for the proposal (refunded on execute/cancel)
+    pub rent_payer: Pubkey,
+    /// When the proposal was queued
+    pub queued_at: i64,
+    /// Earliest time the proposal can be executed
+    pub eta: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl LendingConfigProposal {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // proposal_id
+        LendingConfigUpdate::LEN + // update
+        32 + // rent_payer
+        8 + // queued_at
+        8 + // eta
+        1 + // bump
+        1 + // version
+        16; // reserved
+
+    pub const SEEDS_PREFIX: &'static [u8] = b"lending_config_proposal";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+}
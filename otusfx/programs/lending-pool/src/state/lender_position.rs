@@ -33,6 +33,10 @@ pub struct LenderPosition {
     pub privacy_commitment_count: u32,
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl LenderPosition {
@@ -51,10 +55,15 @@ impl LenderPosition {
         8 + // cumulative_usd1_withdrawn
         1 + // is_private
         4 + // privacy_commitment_count
-        1; // bump
+        1 + // bump
+        1 + // version
+        64; // reserved
 
     pub const SEEDS_PREFIX: &'static [u8] = b"lender_position";
 
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
     /// Calculate interest earned in USD since last update
     /// Formula: interest_usd = principal * APR * time_elapsed / SECONDS_PER_YEAR
     pub fn calculate_interest_usd(&self, current_apr_bps: u64, current_timestamp: i64) -> u64 {
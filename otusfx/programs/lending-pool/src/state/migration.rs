@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use super::{LendingConfig, OutflowLimiter};
+
+/// LendingConfig as first deployed (before the version byte existed)
+///
+/// Later layouts inserted governance fields after `authority`, so these accounts
+/// cannot be read with the current layout; they are recognised by their length.
+#[derive(AnchorDeserialize)]
+pub struct LendingConfigV0 {
+    pub authority: Pubkey,
+    pub usdc_vault: Pubkey,
+    pub usd1_vault: Pubkey,
+    pub otus_vault: Pubkey,
+    pub total_deposited_usdc: u64,
+    pub total_deposited_usd1: u64,
+    pub total_borrowed: u64,
+    pub total_reserves: u64,
+    pub base_interest_rate: u16,
+    pub utilization_multiplier: u16,
+    pub max_utilization_rate: u16,
+    pub reserve_factor: u16,
+    pub last_update_timestamp: i64,
+    pub otus_price_usd: u64,
+    pub bump: u8,
+}
+
+impl LendingConfigV0 {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // usdc_vault
+        32 + // usd1_vault
+        32 + // otus_vault
+        8 + // total_deposited_usdc
+        8 + // total_deposited_usd1
+        8 + // total_borrowed
+        8 + // total_reserves
+        2 + // base_interest_rate
+        2 + // utilization_multiplier
+        2 + // max_utilization_rate
+        2 + // reserve_factor
+        8 + // last_update_timestamp
+        8 + // otus_price_usd
+        1; // bump
+
+    /// Convert to the current layout at version 0, filling added fields with
+    /// initialize_lending's defaults
+    pub fn migrate(self, now: i64) -> LendingConfig {
+        LendingConfig {
+            authority: self.authority,
+            pending_authority: Pubkey::default(),
+            guardian: self.authority,
+            is_paused: false,
+            global_settlement: false,
+            timelock_delay: 48 * 60 * 60, // 48 hours
+            proposal_counter: 0,
+            usdc_vault: self.usdc_vault,
+            usd1_vault: self.usd1_vault,
+            otus_vault: self.otus_vault,
+            total_deposited_usdc: self.total_deposited_usdc,
+            total_deposited_usd1: self.total_deposited_usd1,
+            total_borrowed: self.total_borrowed,
+            total_reserves: self.total_reserves,
+            base_interest_rate: self.base_interest_rate,
+            utilization_multiplier: self.utilization_multiplier,
+            max_utilization_rate: self.max_utilization_rate,
+            reserve_factor: self.reserve_factor,
+            last_update_timestamp: self.last_update_timestamp,
+            otus_price_usd: self.otus_price_usd,
+            reconcile_tolerance: 1_000_000, // $1
+            usdc_outflow_limiter: OutflowLimiter::new(250_000_000_000, 60 * 60, now), // $250k/hour
+            usd1_outflow_limiter: OutflowLimiter::new(250_000_000_000, 60 * 60, now), // $250k/hour
+            outflow_release_delay: 6 * 60 * 60, // 6 hours
+            outflow_counter: 0,
+            total_pending_outflow: 0,
+            bump: self.bump,
+            version: 0,
+            reserved: [0; 128],
+        }
+    }
+}
@@ -1,10 +1,16 @@
 pub mod config;
 pub mod lender_position;
 pub mod borrow_position;
+pub mod config_proposal;
+pub mod rate_limiter;
+pub mod migration;
 
 pub use config::*;
 pub use lender_position::*;
 pub use borrow_position::*;
+pub use config_proposal::*;
+pub use rate_limiter::*;
+pub use migration::*;
 
 use anchor_lang::prelude::*;
 
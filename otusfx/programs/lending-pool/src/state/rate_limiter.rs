@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use otus_common::rate_limit::calculate_bucket_level;
+use super::StablecoinType;
+
+/// Token-bucket limiter on outflows from a lending vault
+/// The bucket refills linearly to `max_outflow` over `window_secs`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OutflowLimiter {
+    /// Maximum outflow per rolling window (0 disables the limiter)
+    pub max_outflow: u64,
+    /// Seconds for an empty bucket to refill completely
+    pub window_secs: i64,
+    /// Outflow currently available without queueing
+    pub available: u64,
+    /// Timestamp of the last refill
+    pub last_update: i64,
+}
+
+impl OutflowLimiter {
+    pub const LEN: usize = 8 + // max_outflow
+        8 + // window_secs
+        8 + // available
+        8; // last_update
+
+    pub fn new(max_outflow: u64, window_secs: i64, now: i64) -> Self {
+        Self {
+            max_outflow,
+            window_secs,
+            available: max_outflow,
+            last_update: now,
+        }
+    }
+
+    /// Refill the bucket up to `now`
+    pub fn refill(&mut self, now: i64) {
+        self.available = calculate_bucket_level(
+            self.available,
+            self.max_outflow,
+            self.window_secs,
+            now.saturating_sub(self.last_update),
+        );
+        self.last_update = now;
+    }
+
+    /// Whether `amount` fits in the bucket at `now` (refills but consumes nothing)
+    pub fn has_capacity(&mut self, amount: u64, now: i64) -> bool {
+        if self.max_outflow == 0 {
+            return true;
+        }
+
+        self.refill(now);
+        amount <= self.available
+    }
+
+    /// Consume `amount` from the bucket if available; returns false if it would exceed the limit
+    pub fn try_consume(&mut self, amount: u64, now: i64) -> bool {
+        if !self.has_capacity(amount, now) {
+            return false;
+        }
+        self.available = self.available.saturating_sub(amount);
+        true
+    }
+
+    /// Consume as much of `amount` as the bucket holds; returns the amount consumed
+    pub fn consume_available(&mut self, amount: u64, now: i64) -> u64 {
+        if self.max_outflow == 0 {
+            return amount;
+        }
+
+        self.refill(now);
+        let consumed = amount.min(self.available);
+        self.available -= consumed;
+        consumed
+    }
+}
+
+/// A withdrawal that exceeded the rate limit, held for delayed release
+#[account]
+pub struct PendingOutflow {
+    /// Sequential outflow ID (from lending_config.outflow_counter)
+    pub outflow_id: u64,
+    /// Vault the funds are released from
+    pub stablecoin_type: StablecoinType,
+    /// Token account that receives the funds
+    pub recipient: Pubkey,
+    /// Amount to release (6 decimals)
+    pub amount: u64,
+    /// Earliest timestamp the outflow can be released
+    pub release_at: i64,
+    /// Account that paid rent for this record (refunded on release)
+    pub rent_payer: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl PendingOutflow {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // outflow_id
+        1 + // stablecoin_type
+        32 + // recipient
+        8 + // amount
+        8 + // release_at
+        32 + // rent_payer
+        1 + // bump
+        1 + // version
+        16; // reserved
+
+    pub const SEEDS_PREFIX: &'static [u8] = b"pending_outflow";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+}
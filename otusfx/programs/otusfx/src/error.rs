@@ -61,4 +61,172 @@ pub enum TradingError {
 
     #[msg("Price cannot be negative")]
     NegativePrice,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Invalid keeper reward bounds")]
+    InvalidKeeperRewardBounds,
+
+    #[msg("Invalid keeper auction parameters")]
+    InvalidKeeperAuction,
+
+    #[msg("Margin health too low to restore leverage")]
+    MarginHealthTooLowToRestore,
+
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Invalid guardian")]
+    InvalidGuardian,
+
+    #[msg("Timelock delay below minimum")]
+    InvalidTimelockDelay,
+
+    #[msg("Config proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Opening positions is paused")]
+    OpenPaused,
+
+    #[msg("Closing positions is paused")]
+    ClosePaused,
+
+    #[msg("Deleverage is paused")]
+    DeleveragePaused,
+
+    #[msg("Order execution is paused")]
+    OrderExecutionPaused,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Global settlement is active")]
+    GlobalSettlementActive,
+
+    #[msg("Global settlement is not active")]
+    GlobalSettlementNotActive,
+
+    #[msg("Settlement price not set for this pair")]
+    SettlementPriceNotSet,
+
+    #[msg("Settlement price already captured for this pair")]
+    SettlementPriceAlreadySet,
+
+    #[msg("Invalid settlement price")]
+    InvalidSettlementPrice,
+
+    #[msg("Outflow exceeds the vault rate limit; queue it for delayed release")]
+    OutflowRateLimited,
+
+    #[msg("Invalid outflow limit parameters")]
+    InvalidOutflowLimit,
+
+    #[msg("Pending outflow release time has not been reached")]
+    OutflowNotReleasable,
+
+    #[msg("Account is not a migratable account of this program")]
+    InvalidMigrationAccount,
+
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Trader has reached the maximum number of open positions")]
+    TooManyOpenPositions,
+
+    #[msg("Invalid max open positions per trader")]
+    InvalidMaxOpenPositions,
+
+    #[msg("Position is still open")]
+    PositionStillOpen,
+
+    #[msg("Invalid fee schedule")]
+    InvalidFeeSchedule,
+
+    #[msg("Invalid Scops NFT proof")]
+    InvalidScopsProof,
+
+    #[msg("Invalid Scops fee discount")]
+    InvalidScopsDiscount,
+
+    #[msg("Invalid volume fee tiers")]
+    InvalidVolumeTiers,
+
+    #[msg("Invalid referral code")]
+    InvalidReferralCode,
+
+    #[msg("Trader is already bound to a referral code")]
+    ReferralAlreadyBound,
+
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+
+    #[msg("Invalid referral fee shares")]
+    InvalidReferralShares,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Insufficient free cross-margin collateral")]
+    InsufficientCrossCollateral,
+
+    #[msg("Position is cross-margined")]
+    CrossMarginPosition,
+
+    #[msg("Accounts must list every cross-margin position with its price cache")]
+    InvalidCrossMarginPositions,
+
+    #[msg("Cross-margin health too low")]
+    CrossMarginHealthTooLow,
+
+    #[msg("A larger position must be deleveraged first")]
+    NotLargestRiskPosition,
+
+    #[msg("Invalid currency risk weights")]
+    InvalidCurrencyRiskWeights,
+
+    #[msg("Portfolio margin is unavailable until currency risk weights are set")]
+    PortfolioMarginUnavailable,
+
+    #[msg("Quote conversion oracle required for this pair")]
+    MissingQuoteOracle,
+
+    #[msg("Leverage exceeds the pair's limit for this position size")]
+    LeverageAboveLimit,
+
+    #[msg("Invalid leverage tiers")]
+    InvalidLeverageTiers,
+
+    #[msg("Volatility was sampled too recently")]
+    VolatilitySampleTooSoon,
+
+    #[msg("Invalid volatility adaptation bounds")]
+    InvalidVolatilityBounds,
+
+    #[msg("Invalid price impact parameters")]
+    InvalidPriceImpact,
+
+    #[msg("Pair is cooling down after a circuit breaker trip")]
+    CircuitBreakerCooldown,
+
+    #[msg("Price moved too far from the last accepted price")]
+    PriceMoveExceedsBreaker,
+
+    #[msg("Invalid circuit breaker parameters")]
+    InvalidCircuitBreaker,
+
+    #[msg("Outflow fits the vault rate limit; send it without a pending outflow")]
+    OutflowWithinLimit,
+
+    #[msg("Pair config account is missing or does not match the proposal's pair")]
+    InvalidPairConfig,
+
+    #[msg("Settlement price is frozen: positions have already settled at it")]
+    SettlementPriceFrozen,
+
+    #[msg("Portfolio margin floor must be at most 10,000 bps")]
+    InvalidPortfolioMarginFloor,
+
+    #[msg("Close every cross position at the settlement price before withdrawing")]
+    CrossPositionsStillOpen,
 }
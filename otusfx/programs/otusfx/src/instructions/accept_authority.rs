@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::TradingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.pending_authority == new_authority.key() @ TradingError::InvalidPendingAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Proposed authority (a multisig or governance PDA signs via CPI)
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let previous_authority = config.authority;
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = Pubkey::default();
+
+    emit_cpi!(AuthorityTransferred {
+        previous_authority,
+        new_authority: config.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Authority transferred: {} -> {}", previous_authority, config.authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
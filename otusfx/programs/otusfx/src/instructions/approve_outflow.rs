@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PendingOutflow};
+
+#[derive(Accounts)]
+pub struct ApproveOutflow<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.guardian == guardian.key() @ TradingError::InvalidGuardian
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PendingOutflow::SEED_PREFIX, &pending_outflow.outflow_id.to_le_bytes()],
+        bump = pending_outflow.bump
+    )]
+    pub pending_outflow: Account<'info, PendingOutflow>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ApproveOutflow>,
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_outflow;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Approval skips the remaining delay; the release is still charged against the
+    // outflow limiter like any other
+    pending.release_at = pending.release_at.min(now);
+
+    msg!("Outflow approved by guardian:");
+    msg!("  Outflow ID: {}", pending.outflow_id);
+    msg!("  Amount: {} USDC", pending.amount);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TraderAccount, ReferralCode};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BindReferralCode<'info> {
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump,
+        constraint = !trader_account.has_referral() @ TradingError::ReferralAlreadyBound
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    #[account(
+        mut,
+        seeds = [ReferralCode::SEED_PREFIX, &referral_code.code],
+        bump = referral_code.bump,
+        constraint = referral_code.referrer != trader.key() @ TradingError::SelfReferral
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    /// Trader binding their account to the code (once, irreversibly)
+    pub trader: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<BindReferralCode>,
+) -> Result<()> {
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.referred_count = referral_code.referred_count
+        .checked_add(1)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    let trader_account = &mut ctx.accounts.trader_account;
+    trader_account.referral_code = referral_code.key();
+
+    emit_cpi!(ReferralCodeBound {
+        trader: trader_account.owner,
+        referral_code: referral_code.key(),
+        referrer: referral_code.referrer,
+    });
+
+    msg!("Referral code bound:");
+    msg!("  Trader: {}", trader_account.owner);
+    msg!("  Code: {}", referral_code.key());
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferralCodeBound {
+    pub trader: Pubkey,
+    pub referral_code: Pubkey,
+    pub referrer: Pubkey,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, ConfigProposal};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ TradingError::InvalidAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            ConfigProposal::SEED_PREFIX,
+            &proposal.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// Receives the proposal's rent
+    /// CHECK: Must match the proposal's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == proposal.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit_cpi!(ConfigUpdateCancelled {
+        proposal_id: proposal.proposal_id,
+        cancelled_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Config update cancelled: proposal {}", proposal.proposal_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub proposal_id: u64,
+    pub cancelled_at: i64,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig};
+use crate::utils::{get_cached_price, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CaptureSettlementPrice<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.global_settlement @ TradingError::GlobalSettlementNotActive
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Anyone can capture the price while the oracle is still valid
+    pub keeper: Signer<'info>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<CaptureSettlementPrice>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pair_config = &mut ctx.accounts.pair_config;
+
+    // The first valid capture wins; governance can still override via set_settlement_price
+    // until the first position settles
+    require!(
+        pair_config.settlement_price == 0,
+        TradingError::SettlementPriceAlreadySet
+    );
+
+    // Staleness and confidence checks reject a broken feed
+    let (price, price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        pair_config.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+    require!(price > 0, TradingError::InvalidSettlementPrice);
+
+    pair_config.settlement_price = price;
+    pair_config.settlement_price_expo = price_expo;
+
+    emit_cpi!(SettlementPriceSet {
+        pair: pair_config.pair as u8,
+        price,
+        price_expo,
+        from_oracle: true,
+    });
+
+    msg!("Settlement price captured:");
+    msg!("  Pair: {:?}", pair_config.pair);
+    msg!("  Price: {} (expo {})", price, price_expo);
+
+    Ok(())
+}
+
+#[event]
+pub struct SettlementPriceSet {
+    pub pair: u8,
+    pub price: i64,
+    pub price_expo: i32,
+    pub from_oracle: bool,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, TraderAccount, PendingOutflow};
+use crate::utils::route_usdc_outflow;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimMarginRefund<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    /// Trader's USDC token account (receives the refund)
+    #[account(
+        mut,
+        constraint = trader_usdc.mint == usdc_vault.mint,
+        constraint = trader_usdc.owner == trader.key()
+    )]
+    pub trader_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Provide to queue the refund for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = trader,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimMarginRefund>,
+) -> Result<()> {
+    // Claims are outflows: held while payouts are held
+    ctx.accounts.config.require_payouts_active()?;
+
+    let amount = ctx.accounts.trader_account.claimable_margin;
+    require!(amount > 0, TradingError::NothingToClaim);
+
+    let queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.trader_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.trader.key(),
+        amount,
+        ctx.accounts.clock.unix_timestamp,
+    )?;
+
+    ctx.accounts.trader_account.claimable_margin = 0;
+    let config = &mut ctx.accounts.config;
+    config.total_margin_owed = config.total_margin_owed
+        .checked_sub(amount)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+
+    emit_cpi!(MarginRefundClaimed {
+        trader: ctx.accounts.trader.key(),
+        amount,
+        queued,
+    });
+
+    msg!("Margin refund claimed:");
+    msg!("  Trader: {}", ctx.accounts.trader.key());
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Queued: {}", queued);
+
+    Ok(())
+}
+
+#[event]
+pub struct MarginRefundClaimed {
+    pub trader: Pubkey,
+    pub amount: u64,
+    pub queued: bool,
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, ReferralCode, PendingOutflow};
+use crate::utils::route_usdc_outflow;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [ReferralCode::SEED_PREFIX, &referral_code.code],
+        bump = referral_code.bump,
+        has_one = referrer
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    /// Referrer's USDC token account (receives the rewards)
+    #[account(
+        mut,
+        constraint = referrer_usdc.mint == usdc_vault.mint,
+        constraint = referrer_usdc.owner == referrer.key()
+    )]
+    pub referrer_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Provide to queue the rewards for delayed release when they exceed the outflow limit
+    #[account(
+        init,
+        payer = referrer,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimReferralRewards>,
+) -> Result<()> {
+    // Claims are outflows: held while payouts are held
+    ctx.accounts.config.require_payouts_active()?;
+
+    let amount = ctx.accounts.referral_code.claimable;
+    require!(amount > 0, TradingError::NothingToClaim);
+
+    let queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.referrer_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.referrer.key(),
+        amount,
+        ctx.accounts.clock.unix_timestamp,
+    )?;
+
+    ctx.accounts.referral_code.claimable = 0;
+    let config = &mut ctx.accounts.config;
+    config.total_referral_owed = config.total_referral_owed
+        .checked_sub(amount)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+
+    emit_cpi!(ReferralRewardsClaimed {
+        referral_code: ctx.accounts.referral_code.key(),
+        referrer: ctx.accounts.referrer.key(),
+        amount,
+        queued,
+    });
+
+    msg!("Referral rewards claimed:");
+    msg!("  Code: {}", ctx.accounts.referral_code.key());
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Queued: {}", queued);
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referral_code: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub queued: bool,
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Mint};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags, TraderAccount, CrossMarginAccount, ReferralCode, VolumeTier, CloseReason};
+use crate::instructions::PositionClosed;
+use crate::utils::{get_cached_price, get_quote_conversion_price, get_quote_settlement_price, usdc_to_otus, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, book_trading_fee,
+    mark_cross_positions, cross_margin_health, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+/// Close a cross position, releasing its margin allocation back to the account's free collateral
+/// Once global settlement is active the pair's settlement price is used and no fee is charged
+///
+/// In portfolio mode a close can remove a hedge, so remaining_accounts must list the
+/// account's other positions as for withdraw_cross_margin (see mark_cross_positions)
+/// and the account must stay healthy without this one.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseCrossPosition<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        close = trader,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = position.cross_margin @ TradingError::InvalidCrossMarginPositions,
+        constraint = position.owner == trader.key() @ TradingError::UnauthorizedClose
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (pause flags, settlement price, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Cross-margin account the position's margin is allocated from
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    /// Position owner (must sign to close)
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    /// Pyth Oracle price cache account (unused once global settlement is active)
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only, unused once settled: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Quote conversion pair's config, for its settlement price (cross pairs during settlement only)
+    #[account(mut)]
+    pub quote_pair_config: Option<Account<'info, PairConfig>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    /// Referral code the trader is bound to (required once bound; credited a share of the fee)
+    #[account(mut)]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<CloseCrossPosition>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pair_config = &ctx.accounts.pair_config;
+    let position = &ctx.accounts.position;
+    let clock = &ctx.accounts.clock;
+
+    let (exit_price, exit_price_expo, quote_price, reason) = if config.global_settlement {
+        require!(
+            pair_config.settlement_price > 0,
+            TradingError::SettlementPriceNotSet
+        );
+        let quote_price = get_quote_settlement_price(
+            position.pair,
+            ctx.accounts.quote_pair_config.as_deref(),
+        )?;
+        (pair_config.settlement_price, pair_config.settlement_price_expo, quote_price, CloseReason::Settlement)
+    } else {
+        // Validate closing is not paused globally or for this pair
+        PauseFlags::require_active(
+            config.paused_actions,
+            pair_config.paused_actions,
+            PauseFlags::CLOSE,
+        )?;
+
+        let (price, price_expo) = get_cached_price(
+            &ctx.accounts.price_cache,
+            position.pair,
+            MAX_STALENESS_SLOTS,
+            config.max_price_confidence_bps,
+            clock,
+        )?;
+        let quote_price = get_quote_conversion_price(
+            &ctx.accounts.pair_config,
+            ctx.accounts.quote_price_cache.as_deref(),
+            MAX_STALENESS_SLOTS,
+            config.max_price_confidence_bps,
+            clock,
+        )?;
+        // Skew-based price impact on the closing trade
+        let price = pair_config.execution_price(
+            price,
+            PairConfig::trade_notional(position.direction, position.size, false),
+        )?;
+        (price, price_expo, quote_price, CloseReason::Manual)
+    };
+
+    // PnL is settled in OTUS, as in close_position
+    let realized_pnl = position.calculate_unrealized_pnl(exit_price, exit_price_expo, quote_price)?;
+    let otus_price_usdc = 1_000_000; // $1.00 in 6 decimals
+    let final_pnl_otus = if realized_pnl >= 0 {
+        usdc_to_otus(realized_pnl as u64, otus_price_usdc)? as i64
+    } else {
+        -(usdc_to_otus(realized_pnl.unsigned_abs(), otus_price_usdc)? as i64)
+    };
+
+    // Close fee comes out of the account's collateral (none during settlement)
+    let close_fee = if reason == CloseReason::Settlement {
+        0
+    } else {
+        // Scops NFT and volume tier discounts (the larger applies)
+        let scops_discount_bps = resolve_scops_discount_bps(
+            config,
+            &ctx.accounts.trader.key(),
+            ctx.accounts.scops_nft.as_ref(),
+            ctx.accounts.scops_mint.as_ref(),
+            ctx.accounts.scops_deposit.as_ref(),
+        )?;
+        let volume_discount_bps = VolumeTier::discount_for(
+            &config.volume_tiers,
+            ctx.accounts.trader_account.rolling_volume(clock.unix_timestamp),
+        );
+        let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+        ctx.accounts.trader_account.record_volume(position.size, clock.unix_timestamp);
+        apply_fee_discount(config.fee_schedule.close_fee(position.size)?, discount_bps)?
+            .min(ctx.accounts.cross_margin.collateral)
+    };
+
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    cross_margin.remove_position(position.position_id, position.margin);
+    cross_margin.collateral -= close_fee;
+    ctx.accounts.pair_config.remove_open_interest(position.direction, position.size);
+
+    if reason == CloseReason::Settlement {
+        // Every later settlement in the pair (and in crosses quoted through it) uses the same prices
+        ctx.accounts.pair_config.settlement_price_frozen = true;
+        if quote_price.is_some() {
+            if let Some(quote_pair_config) = ctx.accounts.quote_pair_config.as_mut() {
+                quote_pair_config.settlement_price_frozen = true;
+            }
+        }
+    }
+
+    // Closing one leg of a netted book can raise the portfolio requirement
+    if cross_margin.portfolio_margin && reason != CloseReason::Settlement {
+        let marks = mark_cross_positions(
+            cross_margin,
+            ctx.remaining_accounts,
+            config.max_price_confidence_bps,
+            clock,
+        )?;
+        let health = cross_margin_health(cross_margin, &marks, config)?;
+        require!(health >= config.min_cross_health, TradingError::CrossMarginHealthTooLow);
+    }
+
+    // The position account is closed on exit; its final record lives on in the event and history
+    // Realized PnL includes any earlier deleverage tiers
+    let lifetime_pnl = position.lifetime_pnl(realized_pnl)?;
+    let record = PositionClosed {
+        owner: position.owner,
+        position_id: position.position_id,
+        pair: position.pair,
+        direction: position.direction,
+        reason,
+        initial_leverage: position.initial_leverage,
+        leverage: position.leverage,
+        initial_margin: position.initial_margin,
+        margin: position.margin,
+        size: position.size,
+        entry_price: position.entry_price,
+        entry_price_expo: position.entry_price_expo,
+        exit_price,
+        exit_price_expo,
+        realized_pnl: lifetime_pnl,
+        final_pnl_otus,
+        fee: close_fee,
+        margin_returned: 0,
+        deleverage_executed: position.deleverage_executed,
+        opened_at: position.opened_at,
+        closed_at: clock.unix_timestamp,
+    };
+    ctx.accounts.trader_account.record_close(
+        position.position_id,
+        &record.try_to_vec()?,
+        lifetime_pnl,
+    )?;
+    emit_cpi!(record);
+
+    let config = &mut ctx.accounts.config;
+    config.open_position_count = config.open_position_count
+        .checked_sub(1)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    config.total_cross_collateral = config.total_cross_collateral
+        .checked_sub(close_fee)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    let (referral_reward, referral_rebate) = book_trading_fee(
+        config,
+        &mut ctx.accounts.trader_account,
+        ctx.accounts.referral_code.as_mut(),
+        close_fee,
+    )?;
+
+    msg!("Cross position closed:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Close Price: {}", exit_price);
+    msg!("  PnL (USDC): {}", realized_pnl);
+    msg!("  PnL (OTUS): {}", final_pnl_otus);
+    msg!("  Close Fee: {} USDC", close_fee);
+    msg!("  Referral Reward: {} USDC", referral_reward);
+    msg!("  Referral Rebate: {} USDC", referral_rebate);
+    msg!("  Free Collateral: {} USDC", ctx.accounts.cross_margin.free_collateral());
+
+    Ok(())
+}
@@ -1,15 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use bootstrap_pool::state::UserDeposit;
 use crate::error::TradingError;
-use crate::state::{TradingConfig, Position};
-use crate::utils::{get_cached_price, usdc_to_otus, PYTH_ORACLE_PROGRAM_ID};
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags, PendingOutflow, TraderAccount, ReferralCode, VolumeTier, FxPair, Direction, CloseReason};
+use crate::utils::{get_cached_price, get_quote_conversion_price, usdc_to_otus, route_usdc_outflow, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, book_trading_fee, PYTH_ORACLE_PROGRAM_ID};
 
 /// Maximum staleness in slots (~5 minutes at 400ms/slot)
 const MAX_STALENESS_SLOTS: u64 = 750;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
     #[account(
+        mut,
         seeds = [TradingConfig::SEED_PREFIX],
         bump = config.bump
     )]
@@ -17,6 +20,7 @@ pub struct ClosePosition<'info> {
 
     #[account(
         mut,
+        close = trader,
         seeds = [
             Position::SEED_PREFIX,
             position.owner.as_ref(),
@@ -24,10 +28,27 @@ pub struct ClosePosition<'info> {
         ],
         bump = position.bump,
         constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = !position.cross_margin @ TradingError::CrossMarginPosition,
         constraint = position.owner == trader.key() @ TradingError::UnauthorizedClose
     )]
     pub position: Account<'info, Position>,
 
+    /// Per-pair config (pause flags, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
     /// Position owner (must sign to close manually)
     #[account(mut)]
     pub trader: Signer<'info>,
@@ -48,18 +69,47 @@ pub struct ClosePosition<'info> {
     pub usdc_vault: Account<'info, TokenAccount>,
 
     /// Pyth Oracle price cache account
-    /// CHECK: Validated by owner check in get_cached_price
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
     #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
         constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
     )]
     pub price_cache: AccountInfo<'info>,
 
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
     /// OTUS treasury for PnL settlement
     /// CHECK: This will be validated by OTUS treasury CPI in production
     #[account(mut)]
     pub otus_treasury: UncheckedAccount<'info>,
 
+    /// Provide to queue the margin for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = trader,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    /// Referral code the trader is bound to (required once bound; credited a share of the fee)
+    #[account(mut)]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
@@ -70,6 +120,13 @@ pub fn handler(
     let position = &mut ctx.accounts.position;
     let clock = &ctx.accounts.clock;
 
+    // Validate closing is not paused globally or for this pair
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::CLOSE,
+    )?;
+
     // Get current price from Pyth Oracle cache
     let (current_price, current_price_expo) = get_cached_price(
         &ctx.accounts.price_cache,
@@ -78,9 +135,22 @@ pub fn handler(
         config.max_price_confidence_bps,
         clock,
     )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Skew-based price impact on the closing trade
+    let exit_price = ctx.accounts.pair_config.execution_price(
+        current_price,
+        PairConfig::trade_notional(position.direction, position.size, false),
+    )?;
 
     // Calculate final PnL in USDC
-    let total_unrealized_pnl = position.calculate_unrealized_pnl(current_price, current_price_expo)?;
+    let total_unrealized_pnl = position.calculate_unrealized_pnl(exit_price, current_price_expo, quote_price)?;
 
     // Calculate final equity
     let final_equity_usdc = if total_unrealized_pnl >= 0 {
@@ -102,21 +172,36 @@ pub fn handler(
         -(usdc_to_otus(total_unrealized_pnl.abs() as u64, otus_price_usdc)? as i64)
     };
 
-    // Return margin to trader
-    let config_seeds = &[
-        TradingConfig::SEED_PREFIX,
-        &[config.bump],
-    ];
-    let signer_seeds = &[&config_seeds[..]];
-
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.usdc_vault.to_account_info(),
-        to: ctx.accounts.trader_usdc.to_account_info(),
-        authority: config.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, position.margin)?;
+    // Close fee is taken from the returned margin and stays in the vault (accrued fees and referral shares)
+    // Scops NFT and volume tier discounts (the larger applies)
+    let scops_discount_bps = resolve_scops_discount_bps(
+        config,
+        &ctx.accounts.trader.key(),
+        ctx.accounts.scops_nft.as_ref(),
+        ctx.accounts.scops_mint.as_ref(),
+        ctx.accounts.scops_deposit.as_ref(),
+    )?;
+    let volume_discount_bps = VolumeTier::discount_for(
+        &config.volume_tiers,
+        ctx.accounts.trader_account.rolling_volume(clock.unix_timestamp),
+    );
+    let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+    let close_fee = apply_fee_discount(config.fee_schedule.close_fee(position.size)?, discount_bps)?
+        .min(position.margin);
+    let margin_returned = position.margin - close_fee;
+    ctx.accounts.trader_account.record_volume(position.size, clock.unix_timestamp);
+
+    // Return margin to trader (queued if over the outflow limit)
+    let queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.trader_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.trader.key(),
+        margin_returned,
+        clock.unix_timestamp,
+    )?;
 
     // TODO: Settle PnL in OTUS via CPI to OTUS treasury
     // For MVP, we're just recording the PnL. In production:
@@ -130,18 +215,92 @@ pub fn handler(
     //     final_pnl_otus,
     // )?;
 
-    // Update position state
-    position.is_open = false;
-    position.closed_at = Some(clock.unix_timestamp);
-    position.final_pnl_otus = Some(final_pnl_otus);
+    // The position account is closed on exit; its final record lives on in the event and history
+    // Realized PnL includes any earlier deleverage tiers
+    let lifetime_pnl = position.lifetime_pnl(total_unrealized_pnl)?;
+    let record = PositionClosed {
+        owner: position.owner,
+        position_id: position.position_id,
+        pair: position.pair,
+        direction: position.direction,
+        reason: CloseReason::Manual,
+        initial_leverage: position.initial_leverage,
+        leverage: position.leverage,
+        initial_margin: position.initial_margin,
+        margin: position.margin,
+        size: position.size,
+        entry_price: position.entry_price,
+        entry_price_expo: position.entry_price_expo,
+        exit_price,
+        exit_price_expo: current_price_expo,
+        realized_pnl: lifetime_pnl,
+        final_pnl_otus,
+        fee: close_fee,
+        margin_returned,
+        deleverage_executed: position.deleverage_executed,
+        opened_at: position.opened_at,
+        closed_at: clock.unix_timestamp,
+    };
+    ctx.accounts.trader_account.record_close(
+        position.position_id,
+        &record.try_to_vec()?,
+        lifetime_pnl,
+    )?;
+    emit_cpi!(record);
+
+    ctx.accounts.pair_config.remove_open_interest(position.direction, position.size);
+
+    let config = &mut ctx.accounts.config;
+    config.open_position_count = config.open_position_count
+        .checked_sub(1)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    config.total_open_margin = config.total_open_margin
+        .saturating_sub(position.margin);
+    let (referral_reward, referral_rebate) = book_trading_fee(
+        config,
+        &mut ctx.accounts.trader_account,
+        ctx.accounts.referral_code.as_mut(),
+        close_fee,
+    )?;
 
     msg!("Position closed:");
     msg!("  Position ID: {}", position.position_id);
-    msg!("  Close Price: {}", current_price);
+    msg!("  Oracle Price: {}", current_price);
+    msg!("  Close Price: {}", exit_price);
     msg!("  PnL (USDC): {}", total_unrealized_pnl);
     msg!("  PnL (OTUS): {}", final_pnl_otus);
     msg!("  Final Equity: {} USDC", final_equity_usdc);
-    msg!("  Margin Returned: {} USDC", position.margin);
+    msg!("  Close Fee: {} USDC", close_fee);
+    msg!("  Referral Reward: {} USDC", referral_reward);
+    msg!("  Referral Rebate: {} USDC", referral_rebate);
+    msg!("  Margin Returned: {} USDC", margin_returned);
+    msg!("  Margin Queued: {}", queued);
 
     Ok(())
 }
+
+/// Final record of a position whose account has been closed
+#[event]
+pub struct PositionClosed {
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub pair: FxPair,
+    pub direction: Direction,
+    pub reason: CloseReason,
+    pub initial_leverage: u8,
+    pub leverage: u8,
+    pub initial_margin: u64,
+    pub margin: u64,
+    pub size: u64,
+    pub entry_price: i64,
+    pub entry_price_expo: i32,
+    pub exit_price: i64,
+    pub exit_price_expo: i32,
+    pub realized_pnl: i64,
+    pub final_pnl_otus: i64,
+    pub fee: u64,
+    pub margin_returned: u64,
+    pub deleverage_executed: [bool; 4],
+    pub opened_at: i64,
+    pub closed_at: i64,
+}
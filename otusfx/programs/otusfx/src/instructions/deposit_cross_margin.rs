@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, CrossMarginAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositCrossMargin<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = !config.global_settlement @ TradingError::GlobalSettlementActive
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, owner.key().as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    pub owner: Signer<'info>,
+
+    /// Owner's USDC token account (source of collateral)
+    #[account(
+        mut,
+        constraint = owner_usdc.mint == usdc_vault.mint,
+        constraint = owner_usdc.owner == owner.key()
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<DepositCrossMargin>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, TradingError::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_usdc.to_account_info(),
+        to: ctx.accounts.usdc_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    cross_margin.collateral = cross_margin.collateral
+        .checked_add(amount)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    let config = &mut ctx.accounts.config;
+    config.total_cross_collateral = config.total_cross_collateral
+        .checked_add(amount)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    emit_cpi!(CrossMarginDeposited {
+        owner: cross_margin.owner,
+        amount,
+        collateral: cross_margin.collateral,
+    });
+
+    msg!("Cross-margin deposit:");
+    msg!("  Owner: {}", cross_margin.owner);
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Collateral: {} USDC", cross_margin.collateral);
+
+    Ok(())
+}
+
+#[event]
+pub struct CrossMarginDeposited {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub collateral: u64,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PauseFlags};
+use crate::utils::calculate_settlement_payout_bps;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnterGlobalSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = (
+            signer.key() == config.authority || signer.key() == config.guardian
+        ) @ TradingError::InvalidAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Authority or guardian
+    pub signer: Signer<'info>,
+
+    /// Protocol USDC vault (balance fixes the pro-rata payout)
+    #[account(
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<EnterGlobalSettlement>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = &ctx.accounts.clock;
+
+    // One-way: there is no instruction to leave settlement
+    require!(!config.global_settlement, TradingError::GlobalSettlementActive);
+
+    let vault_balance = ctx.accounts.usdc_vault.amount;
+    // Isolated margin and cross-margin collateral share the same pro-rata haircut, backed
+    // only by what is left once the vault's other liabilities are set aside
+    let margin_backing = config.settlement_backing(vault_balance)?;
+    let margin_claims = config.total_open_margin
+        .checked_add(config.total_cross_collateral)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    let payout_bps = calculate_settlement_payout_bps(margin_backing, margin_claims);
+
+    config.global_settlement = true;
+    config.settled_at = clock.unix_timestamp;
+    config.settlement_payout_bps = payout_bps;
+    config.paused_actions = PauseFlags::ALL;
+
+    emit_cpi!(GlobalSettlementEntered {
+        signer: ctx.accounts.signer.key(),
+        vault_balance,
+        margin_backing,
+        total_open_margin: config.total_open_margin,
+        open_position_count: config.open_position_count,
+        payout_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Global settlement entered:");
+    msg!("  Vault Balance: {} USDC", vault_balance);
+    msg!("  Margin Backing: {} USDC", margin_backing);
+    msg!("  Open Margin: {} USDC", config.total_open_margin);
+    msg!("  Open Positions: {}", config.open_position_count);
+    msg!("  Payout: {} bps", payout_bps);
+
+    Ok(())
+}
+
+#[event]
+pub struct GlobalSettlementEntered {
+    pub signer: Pubkey,
+    pub vault_balance: u64,
+    pub margin_backing: u64,
+    pub total_open_margin: u64,
+    pub open_position_count: u64,
+    pub payout_bps: u16,
+    pub timestamp: i64,
+}
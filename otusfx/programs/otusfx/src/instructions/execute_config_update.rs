@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, ConfigProposal, ConfigUpdate, PairConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            ConfigProposal::SEED_PREFIX,
+            &proposal.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// Receives the proposal's rent
+    /// CHECK: Must match the proposal's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == proposal.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Config of the pair a per-pair update targets (required when the proposal has one)
+    #[account(mut)]
+    pub pair_config: Option<Account<'info, PairConfig>>,
+
+    /// Anyone can execute once the timelock has elapsed
+    pub executor: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= proposal.eta,
+        TradingError::TimelockNotElapsed
+    );
+
+    proposal.update.apply(&mut ctx.accounts.config)?;
+    if let Some(pair_update) = &proposal.update.pair {
+        let pair_config = ctx.accounts.pair_config.as_mut().ok_or(TradingError::InvalidPairConfig)?;
+        pair_update.apply(&ctx.accounts.config, pair_config)?;
+    }
+
+    emit_cpi!(ConfigUpdated {
+        proposal_id: proposal.proposal_id,
+        update: proposal.update.clone(),
+        executed_at: clock.unix_timestamp,
+    });
+
+    msg!("Config update executed: proposal {}", proposal.proposal_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub proposal_id: u64,
+    pub update: ConfigUpdate,
+    pub executed_at: i64,
+}
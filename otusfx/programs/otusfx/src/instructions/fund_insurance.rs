@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::TradingError;
+use crate::state::TradingConfig;
+
+#[derive(Accounts)]
+pub struct FundInsurance<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Anyone can top up the insurance fund
+    pub funder: Signer<'info>,
+
+    /// Funder's USDC token account
+    #[account(
+        mut,
+        constraint = funder_usdc.mint == usdc_vault.mint,
+        constraint = funder_usdc.owner == funder.key()
+    )]
+    pub funder_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<FundInsurance>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, TradingError::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_usdc.to_account_info(),
+        to: ctx.accounts.usdc_vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let config = &mut ctx.accounts.config;
+    config.insurance_fund = config.insurance_fund
+        .checked_add(amount)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    msg!("Insurance fund topped up:");
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Insurance Fund: {} USDC", config.insurance_fund);
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use crate::state::TradingConfig;
+use crate::state::{TradingConfig, OutflowLimiter, FeeSchedule, VolumeTier};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -67,20 +67,58 @@ pub fn handler(
     let config = &mut ctx.accounts.config;
 
     config.authority = ctx.accounts.authority.key();
+    config.pending_authority = Pubkey::default();
+    config.guardian = ctx.accounts.authority.key();
+    config.timelock_delay = 48 * 60 * 60; // 48 hours
+    config.proposal_counter = 0;
     config.otus_treasury = ctx.accounts.otus_treasury.key();
     config.usdc_vault = ctx.accounts.usdc_vault.key();
     config.usd1_vault = ctx.accounts.usd1_vault.key();
     config.trading_fee_bps = trading_fee_bps;
+    config.fee_schedule = FeeSchedule::flat(trading_fee_bps);
+    config.scops_discount_bps = [0; 5];
+    config.volume_tiers = [VolumeTier::default(); 4];
+    config.referral_share_bps = 0;
+    config.referral_rebate_bps = 0;
+    config.total_referral_owed = 0;
+    config.total_cross_collateral = 0;
+    config.currency_risk_weights_bps = [0; 8];
+    config.total_margin_owed = 0;
+    config.portfolio_margin_floor_bps = 100; // 1%
+    config.min_cross_health = 80;
     config.keeper_fee_bps = keeper_fee_bps;
+    config.keeper_fee_start_bps = keeper_fee_bps / 5;
+    config.keeper_fee_ramp_secs = 300; // 5 minutes
     config.max_leverage = max_leverage;
     config.min_leverage = min_leverage;
     config.deleverage_thresholds = [50, 35, 25, 15];
+    config.min_restore_health = 80;
     config.pyth_program = ctx.accounts.pyth_program.key();
     config.max_price_age = 60; // 60 seconds
     config.max_price_confidence_bps = 200; // 2%
-    config.is_paused = false;
+    config.paused_actions = 0;
     config.position_counter = 0;
+    config.insurance_fund = 0;
+    config.accrued_fees = 0;
+    config.reconcile_tolerance = 1_000_000; // $1
+    config.usdc_outflow_limiter = OutflowLimiter::new(
+        100_000_000_000, // $100k
+        60 * 60,         // per hour
+        Clock::get()?.unix_timestamp,
+    );
+    config.outflow_release_delay = 6 * 60 * 60; // 6 hours
+    config.outflow_counter = 0;
+    config.total_pending_outflow = 0;
+    config.min_keeper_reward = [500_000; 4]; // $0.50
+    config.max_keeper_reward = [250_000_000; 4]; // $250
+    config.open_position_count = 0;
+    config.total_open_margin = 0;
+    config.global_settlement = false;
+    config.settled_at = 0;
+    config.settlement_payout_bps = 10_000;
     config.bump = ctx.bumps.config;
+    config.version = TradingConfig::VERSION;
+    config.max_open_positions_per_trader = TradingConfig::DEFAULT_MAX_OPEN_POSITIONS;
 
     msg!("Trading engine initialized");
     msg!("Trading fee: {} bps", trading_fee_bps);
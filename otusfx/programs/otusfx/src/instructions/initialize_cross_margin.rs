@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::CrossMarginAccount;
+
+#[derive(Accounts)]
+pub struct InitializeCrossMargin<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = CrossMarginAccount::LEN,
+        seeds = [CrossMarginAccount::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    /// Trader opting in to cross margin
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeCrossMargin>,
+) -> Result<()> {
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    cross_margin.owner = ctx.accounts.owner.key();
+    cross_margin.collateral = 0;
+    cross_margin.allocated_margin = 0;
+    cross_margin.position_ids = Vec::new();
+    cross_margin.bump = ctx.bumps.cross_margin;
+    cross_margin.version = CrossMarginAccount::VERSION;
+    cross_margin.portfolio_margin = false;
+
+    msg!("Cross-margin account initialized: {}", cross_margin.owner);
+
+    Ok(())
+}
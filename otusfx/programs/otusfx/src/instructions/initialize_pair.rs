@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig, LeverageTier, FxPair};
+
+#[derive(Accounts)]
+#[instruction(pair: u8)]
+pub struct InitializePair<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ TradingError::InvalidAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PairConfig::LEN,
+        seeds = [PairConfig::SEED_PREFIX, &[pair]],
+        bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializePair>,
+    pair: u8,
+    price_feed: Pubkey,
+    quote_price_feed: Pubkey,
+) -> Result<()> {
+    let fx_pair = FxPair::from_u8(pair)
+        .ok_or(TradingError::InvalidPair)?;
+
+    let pair_config = &mut ctx.accounts.pair_config;
+    pair_config.pair = fx_pair;
+    pair_config.paused_actions = 0;
+    pair_config.settlement_price = 0;
+    pair_config.settlement_price_expo = 0;
+    pair_config.bump = ctx.bumps.pair_config;
+    pair_config.version = PairConfig::VERSION;
+    pair_config.max_leverage = 0;
+    pair_config.leverage_tiers = [LeverageTier::default(); 4];
+    pair_config.volatility_bps = 0;
+    pair_config.last_volatility_price = 0;
+    pair_config.last_volatility_update = 0;
+    pair_config.reference_volatility_bps = 0;
+    pair_config.min_leverage_scale_bps = 10_000;
+    pair_config.max_threshold_scale_bps = 10_000;
+    pair_config.long_open_interest = 0;
+    pair_config.short_open_interest = 0;
+    pair_config.skew_scale = 0;
+    pair_config.max_price_impact_bps = 0;
+    pair_config.last_accepted_price = 0;
+    pair_config.last_accepted_at = 0;
+    pair_config.breaker_max_move_bps = 0;
+    pair_config.breaker_window_secs = 0;
+    pair_config.breaker_cooldown_secs = 0;
+    pair_config.breaker_tripped_until = 0;
+    pair_config.price_feed = price_feed;
+    pair_config.quote_price_feed = quote_price_feed;
+
+    msg!("Pair initialized: {:?}", fx_pair);
+    msg!("  Price Feed: {}", price_feed);
+    msg!("  Quote Price Feed: {}", quote_price_feed);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::TraderAccount;
+
+#[derive(Accounts)]
+pub struct InitializeTraderAccount<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = TraderAccount::LEN,
+        seeds = [TraderAccount::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Trader the index is created for
+    /// CHECK: Only used as a PDA seed; anyone may create a trader's index
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeTraderAccount>,
+) -> Result<()> {
+    let trader_account = &mut ctx.accounts.trader_account;
+    trader_account.owner = ctx.accounts.owner.key();
+    trader_account.open_position_ids = Vec::new();
+    trader_account.total_opened = 0;
+    trader_account.total_closed = 0;
+    trader_account.history_hash = [0; 32];
+    trader_account.realized_pnl = 0;
+    trader_account.bump = ctx.bumps.trader_account;
+    trader_account.version = TraderAccount::VERSION;
+    trader_account.volume_buckets = [0; TraderAccount::VOLUME_WINDOW_DAYS];
+    trader_account.volume_day = 0;
+    trader_account.referral_code = Pubkey::default();
+    trader_account.claimable_rebate = 0;
+    trader_account.claimable_margin = 0;
+
+    msg!("Trader account initialized: {}", trader_account.owner);
+
+    Ok(())
+}
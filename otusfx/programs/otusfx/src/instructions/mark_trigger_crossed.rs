@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags};
+use crate::utils::{get_cached_price, has_crossed_trigger, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct MarkTriggerCrossed<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = !position.cross_margin @ TradingError::CrossMarginPosition
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (pause flags, volatility-scaled thresholds)
+    #[account(
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Anyone can record a crossing (typically the keeper that will later deleverage)
+    pub keeper: Signer<'info>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<MarkTriggerCrossed>,
+    tier: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let position = &mut ctx.accounts.position;
+    let clock = &ctx.accounts.clock;
+
+    // Crossings feed the deleverage auction, so they share its pause
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::DELEVERAGE,
+    )?;
+
+    // Validate tier
+    require!(tier < 4, TradingError::InvalidDeleverageTier);
+
+    // Nothing to auction once the tier has executed
+    require!(
+        !position.deleverage_executed[tier as usize],
+        TradingError::DeleverageTierAlreadyExecuted
+    );
+
+    // Get current price from Pyth Oracle cache
+    let (current_price, _) = get_cached_price(
+        &ctx.accounts.price_cache,
+        position.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: bad ticks and cool-downs do not start the keeper auction
+    ctx.accounts.pair_config.require_price_within_breaker(current_price, clock.unix_timestamp)?;
+
+    let thresholds = ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds);
+    let trigger_price = position.effective_trigger_price(tier, &thresholds)?;
+    let crossed = has_crossed_trigger(current_price, trigger_price, position.direction);
+    let crossed_at = &mut position.trigger_crossed_at[tier as usize];
+
+    if crossed && *crossed_at == 0 {
+        // Start the keeper auction
+        *crossed_at = clock.unix_timestamp;
+    } else if !crossed && *crossed_at != 0 {
+        // Price recovered: reset so a later crossing restarts the auction
+        *crossed_at = 0;
+    }
+
+    msg!("Trigger crossing recorded:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Tier: {}", tier);
+    msg!("  Current Price: {}", current_price);
+    msg!("  Trigger Price: {}", trigger_price);
+    msg!("  Crossed At: {}", position.trigger_crossed_at[tier as usize]);
+
+    Ok(())
+}
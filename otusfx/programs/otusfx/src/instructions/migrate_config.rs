@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, TradingConfigV0};
+use otus_common::migration::{
+    check_migration_account, grow_for_migration, load_for_migration, load_legacy, store_migrated,
+};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Config written with an older layout
+    /// CHECK: Owner and discriminator are validated in load_for_migration
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// Current authority (config migrations are admin only); pays any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<MigrateConfig>,
+) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+
+    // v0 (baseline) configs predate the version byte, and the current layout inserts fields
+    // mid-struct, so they are recognised by length and converted field by field
+    let mut config: TradingConfig = if info.data_len() == TradingConfigV0::LEN {
+        check_migration_account::<TradingConfig, _>(
+            &info,
+            &crate::ID,
+            TradingError::InvalidMigrationAccount,
+        )?;
+        let legacy: TradingConfigV0 = load_legacy(&info)?;
+        grow_for_migration(
+            &info,
+            TradingConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+        legacy.migrate(Clock::get()?.unix_timestamp)
+    } else {
+        load_for_migration(
+            &info,
+            &crate::ID,
+            TradingConfig::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            TradingError::InvalidMigrationAccount,
+        )?
+    };
+
+    // Only the authority recorded in the account may migrate it
+    require!(
+        config.authority == ctx.accounts.payer.key(),
+        TradingError::InvalidAuthority
+    );
+
+    let from_version = config.version;
+    require!(from_version < TradingConfig::VERSION, TradingError::AccountAlreadyMigrated);
+
+    // v0 -> v1: every field after the baseline's is filled with its default (see TradingConfigV0)
+    config.version = TradingConfig::VERSION;
+
+    store_migrated(&info, &config)?;
+
+    msg!("Config migrated:");
+    msg!("  Account: {}", info.key());
+    msg!("  From Version: {}", from_version);
+    msg!("  To Version: {}", TradingConfig::VERSION);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PositionV0};
+use otus_common::migration::{
+    check_migration_account, grow_for_migration, load_for_migration, load_legacy, store_migrated,
+};
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    /// Config (already migrated); re-registers open baseline positions
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Position written with an older layout
+    /// CHECK: Owner and discriminator are validated in the migration helpers
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    /// Pays any additional rent (anyone can migrate)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<MigratePosition>,
+) -> Result<()> {
+    let info = ctx.accounts.position.to_account_info();
+
+    // v0 (baseline) positions predate trigger_crossed_at, which was inserted mid-struct,
+    // so they are recognised by length and converted field by field
+    let mut position: Position = if info.data_len() == PositionV0::LEN {
+        check_migration_account::<Position, _>(
+            &info,
+            &crate::ID,
+            TradingError::InvalidMigrationAccount,
+        )?;
+        let legacy: PositionV0 = load_legacy(&info)?;
+        grow_for_migration(
+            &info,
+            Position::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+        legacy.migrate()
+    } else {
+        load_for_migration(
+            &info,
+            &crate::ID,
+            Position::LEN,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            TradingError::InvalidMigrationAccount,
+        )?
+    };
+
+    let from_version = position.version;
+    require!(from_version < Position::VERSION, TradingError::AccountAlreadyMigrated);
+
+    // v0 -> v1: trigger_crossed_at inserted; version, cross-margin, entry quote price,
+    // realized PnL and reserved appended (see PositionV0)
+    // The config's open position totals started at zero when it left the baseline layout,
+    // so count each open baseline position back in
+    if from_version < 1 && position.is_open {
+        let config = &mut ctx.accounts.config;
+        config.open_position_count = config.open_position_count
+            .checked_add(1)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        config.total_open_margin = config.total_open_margin
+            .checked_add(position.margin)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+    }
+    position.version = Position::VERSION;
+
+    store_migrated(&info, &position)?;
+
+    msg!("Position migrated:");
+    msg!("  Account: {}", info.key());
+    msg!("  From Version: {}", from_version);
+    msg!("  To Version: {}", Position::VERSION);
+
+    Ok(())
+}
@@ -2,10 +2,86 @@ pub mod initialize;
 pub mod open_position;
 pub mod trigger_deleverage;
 pub mod close_position;
-pub mod update_config;
+pub mod reclaim_position;
+pub mod quote_open;
+pub mod quote_close;
+pub mod position_health;
+pub mod queue_config_update;
+pub mod execute_config_update;
+pub mod cancel_config_update;
+pub mod set_paused;
+pub mod set_pair_paused;
+pub mod initialize_pair;
+pub mod initialize_trader_account;
+pub mod enter_global_settlement;
+pub mod capture_settlement_price;
+pub mod set_settlement_price;
+pub mod settle_position;
+pub mod reconcile;
+pub mod release_outflow;
+pub mod approve_outflow;
+pub mod fund_insurance;
+pub mod mark_trigger_crossed;
+pub mod restore_leverage;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod migrate_config;
+pub mod migrate_position;
+pub mod register_referral_code;
+pub mod bind_referral_code;
+pub mod claim_referral_rewards;
+pub mod claim_referral_rebate;
+pub mod claim_margin_refund;
+pub mod initialize_cross_margin;
+pub mod deposit_cross_margin;
+pub mod withdraw_cross_margin;
+pub mod open_cross_position;
+pub mod close_cross_position;
+pub mod trigger_cross_deleverage;
+pub mod set_portfolio_margin;
+pub mod update_pair_volatility;
+pub mod update_price_breaker;
 
 pub use initialize::*;
 pub use open_position::*;
 pub use trigger_deleverage::*;
 pub use close_position::*;
-pub use update_config::*;
+pub use reclaim_position::*;
+pub use quote_open::*;
+pub use quote_close::*;
+pub use position_health::*;
+pub use queue_config_update::*;
+pub use execute_config_update::*;
+pub use cancel_config_update::*;
+pub use set_paused::*;
+pub use set_pair_paused::*;
+pub use initialize_pair::*;
+pub use initialize_trader_account::*;
+pub use enter_global_settlement::*;
+pub use capture_settlement_price::*;
+pub use set_settlement_price::*;
+pub use settle_position::*;
+pub use reconcile::*;
+pub use release_outflow::*;
+pub use approve_outflow::*;
+pub use fund_insurance::*;
+pub use mark_trigger_crossed::*;
+pub use restore_leverage::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use migrate_config::*;
+pub use migrate_position::*;
+pub use register_referral_code::*;
+pub use bind_referral_code::*;
+pub use claim_referral_rewards::*;
+pub use claim_referral_rebate::*;
+pub use claim_margin_refund::*;
+pub use initialize_cross_margin::*;
+pub use deposit_cross_margin::*;
+pub use withdraw_cross_margin::*;
+pub use open_cross_position::*;
+pub use close_cross_position::*;
+pub use trigger_cross_deleverage::*;
+pub use set_portfolio_margin::*;
+pub use update_pair_volatility::*;
+pub use update_price_breaker::*;
@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Mint};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, TraderAccount, CrossMarginAccount, ReferralCode, VolumeTier, PauseFlags, FxPair, Direction};
+use crate::instructions::PositionOpened;
+use crate::utils::{
+    get_cached_price, get_quote_conversion_price, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps,
+    book_trading_fee, mark_cross_positions, CrossPositionMark, cross_margin_health, PYTH_ORACLE_PROGRAM_ID,
+};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+/// Open a position whose margin is allocated from the trader's cross-margin account
+///
+/// Remaining accounts: (position, pair config, price cache[, quote price cache]) for every
+/// already open cross position, used to check the account stays healthy with the new position.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(pair: u8)]
+pub struct OpenCrossPosition<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        init,
+        payer = trader,
+        space = Position::LEN,
+        seeds = [
+            Position::SEED_PREFIX,
+            trader.key().as_ref(),
+            &config.position_counter.to_le_bytes()
+        ],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (pause flags, leverage limits, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Collateral the position's margin is allocated from
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    /// Referral code the trader is bound to (required once bound; credited a share of the fee)
+    #[account(mut)]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<OpenCrossPosition>,
+    pair: u8,
+    direction: u8,
+    margin: u64,
+    leverage: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let clock = &ctx.accounts.clock;
+
+    // Validate opening is not paused globally or for this pair
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::OPEN,
+    )?;
+
+    // Validate leverage
+    require!(
+        leverage >= config.min_leverage && leverage <= config.max_leverage,
+        TradingError::InvalidLeverage
+    );
+
+    // Validate pair
+    let fx_pair = FxPair::from_u8(pair)
+        .ok_or(TradingError::InvalidPair)?;
+
+    // Validate direction
+    let dir = Direction::from_u8(direction)
+        .ok_or(TradingError::InvalidDirection)?;
+
+    // Validate margin
+    require!(margin > 0, TradingError::InsufficientMargin);
+
+    // Mark the account's existing positions before adding the new one
+    let mut marks = mark_cross_positions(
+        &ctx.accounts.cross_margin,
+        ctx.remaining_accounts,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Record the position in the trader's index (enforces the per-trader limit)
+    ctx.accounts.trader_account.add_position(
+        config.position_counter,
+        config.max_open_positions_per_trader,
+    )?;
+
+    // Get current price from Pyth Oracle cache
+    let (current_price, price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        fx_pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: reject bad ticks and pause during a cool-down
+    ctx.accounts.pair_config.accept_price(current_price, clock.unix_timestamp)?;
+
+    // Calculate position size (notional value)
+    let size = margin
+        .checked_mul(leverage as u64)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    // Per-pair cap and size-tiered leverage curve
+    ctx.accounts.pair_config.require_leverage(leverage, size, config.max_leverage)?;
+
+    // Skew-based price impact: the entry moves against trades that grow the pair's imbalance
+    let entry_price = ctx.accounts.pair_config.execution_price(
+        current_price,
+        PairConfig::trade_notional(dir, size, true),
+    )?;
+
+    // Calculate trading fee
+    // Scops NFT and volume tier discounts (the larger applies)
+    let scops_discount_bps = resolve_scops_discount_bps(
+        config,
+        &ctx.accounts.trader.key(),
+        ctx.accounts.scops_nft.as_ref(),
+        ctx.accounts.scops_mint.as_ref(),
+        ctx.accounts.scops_deposit.as_ref(),
+    )?;
+    let volume_discount_bps = VolumeTier::discount_for(
+        &config.volume_tiers,
+        ctx.accounts.trader_account.rolling_volume(clock.unix_timestamp),
+    );
+    let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+    let trading_fee = apply_fee_discount(config.fee_schedule.open_fee(size)?, discount_bps)?;
+    ctx.accounts.trader_account.record_volume(size, clock.unix_timestamp);
+
+    // Fee comes out of the collateral, then the margin is allocated from what is left free
+    // (portfolio margin only requires the account to stay healthy)
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    let available = if cross_margin.portfolio_margin {
+        cross_margin.collateral
+    } else {
+        cross_margin.free_collateral()
+    };
+    require!(trading_fee <= available, TradingError::InsufficientCrossCollateral);
+    cross_margin.collateral -= trading_fee;
+    cross_margin.add_position(config.position_counter, margin)?;
+
+    // The account must stay healthy across every position, the new one included
+    marks.push(CrossPositionMark {
+        position_id: config.position_counter,
+        pair: fx_pair,
+        direction: dir,
+        size,
+        unrealized_pnl: 0,
+        deleverage_executed: [false; 4],
+    });
+    let health = cross_margin_health(cross_margin, &marks, config)?;
+    require!(health >= config.min_cross_health, TradingError::CrossMarginHealthTooLow);
+
+    // Initialize position
+    // Cross positions have no per-position triggers; deleverage works off account health
+    let position = &mut ctx.accounts.position;
+    position.owner = ctx.accounts.trader.key();
+    position.position_id = config.position_counter;
+    position.is_open = true;
+    position.pair = fx_pair;
+    position.direction = dir;
+    position.leverage = leverage;
+    position.initial_leverage = leverage;
+    position.margin = margin;
+    position.initial_margin = margin;
+    position.size = size;
+    position.entry_price = entry_price;
+    position.entry_price_expo = price_expo;
+    position.trigger_prices = [0; 4];
+    position.deleverage_executed = [false; 4];
+    position.trigger_crossed_at = [0; 4];
+    position.opened_at = clock.unix_timestamp;
+    position.closed_at = None;
+    position.final_pnl_otus = None;
+    position.bump = ctx.bumps.position;
+    position.version = Position::VERSION;
+    position.cross_margin = true;
+    position.entry_quote_price = quote_price.map_or(0, |(price, _)| price);
+    position.entry_quote_price_expo = quote_price.map_or(0, |(_, expo)| expo);
+    position.realized_pnl = 0;
+
+    ctx.accounts.pair_config.add_open_interest(dir, size)?;
+
+    // Increment position counter; cross margin is tracked as account collateral, not open margin
+    let config = &mut ctx.accounts.config;
+    config.position_counter = config.position_counter
+        .checked_add(1)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.open_position_count = config.open_position_count
+        .checked_add(1)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.total_cross_collateral = config.total_cross_collateral
+        .checked_sub(trading_fee)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    let (referral_reward, referral_rebate) = book_trading_fee(
+        config,
+        &mut ctx.accounts.trader_account,
+        ctx.accounts.referral_code.as_mut(),
+        trading_fee,
+    )?;
+
+    emit_cpi!(PositionOpened {
+        owner: position.owner,
+        position_id: position.position_id,
+        pair: fx_pair,
+        direction: dir,
+        leverage,
+        margin,
+        size,
+        entry_price,
+        entry_price_expo: price_expo,
+        trading_fee,
+        trigger_prices: position.trigger_prices,
+        opened_at: clock.unix_timestamp,
+    });
+
+    msg!("Cross position opened:");
+    msg!("  ID: {}", position.position_id);
+    msg!("  Pair: {:?}", fx_pair);
+    msg!("  Direction: {:?}", dir);
+    msg!("  Margin: {} USDC", margin);
+    msg!("  Leverage: {}x", leverage);
+    msg!("  Size: {} USDC", size);
+    msg!("  Oracle Price: {}", current_price);
+    msg!("  Entry Price: {}", entry_price);
+    msg!("  Trading Fee: {} USDC", trading_fee);
+    msg!("  Referral Reward: {} USDC", referral_reward);
+    msg!("  Referral Rebate: {} USDC", referral_rebate);
+    msg!("  Account Health: {}%", health);
+
+    Ok(())
+}
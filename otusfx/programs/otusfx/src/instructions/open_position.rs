@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
+use bootstrap_pool::state::UserDeposit;
 use crate::error::TradingError;
-use crate::state::{TradingConfig, Position, FxPair, Direction};
-use crate::utils::{get_cached_price, get_fx_pair_id, calculate_trading_fee, calculate_trigger_prices, PYTH_ORACLE_PROGRAM_ID};
+use crate::state::{TradingConfig, Position, PairConfig, TraderAccount, ReferralCode, VolumeTier, PauseFlags, FxPair, Direction, QuoteConversion};
+use crate::utils::{get_cached_price, get_quote_conversion_price, get_fx_pair_id, calculate_trigger_prices, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, book_trading_fee, PYTH_ORACLE_PROGRAM_ID};
 
 /// Maximum staleness in slots (~5 minutes at 400ms/slot)
 const MAX_STALENESS_SLOTS: u64 = 750;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(pair: u8)]
 pub struct OpenPosition<'info> {
     #[account(
         mut,
@@ -29,6 +32,22 @@ pub struct OpenPosition<'info> {
     )]
     pub position: Account<'info, Position>,
 
+    /// Per-pair config (pause flags, leverage limits, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
     #[account(mut)]
     pub trader: Signer<'info>,
 
@@ -48,12 +67,30 @@ pub struct OpenPosition<'info> {
     pub usdc_vault: Account<'info, TokenAccount>,
 
     /// Pyth Oracle price cache account
-    /// CHECK: Validated by owner check in get_cached_price
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
     #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
         constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
     )]
     pub price_cache: AccountInfo<'info>,
 
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    /// Referral code the trader is bound to (required once bound; credited a share of the fee)
+    #[account(mut)]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
@@ -69,8 +106,12 @@ pub fn handler(
     let config = &ctx.accounts.config;
     let clock = &ctx.accounts.clock;
 
-    // Validate trading is not paused
-    require!(!config.is_paused, TradingError::TradingPaused);
+    // Validate opening is not paused globally or for this pair
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::OPEN,
+    )?;
 
     // Validate leverage
     require!(
@@ -89,6 +130,12 @@ pub fn handler(
     // Validate margin
     require!(margin > 0, TradingError::InsufficientMargin);
 
+    // Record the position in the trader's index (enforces the per-trader limit)
+    ctx.accounts.trader_account.add_position(
+        config.position_counter,
+        config.max_open_positions_per_trader,
+    )?;
+
     // Get current price from Pyth Oracle cache
     let (current_price, price_expo) = get_cached_price(
         &ctx.accounts.price_cache,
@@ -97,14 +144,47 @@ pub fn handler(
         config.max_price_confidence_bps,
         clock,
     )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: reject bad ticks and pause during a cool-down
+    ctx.accounts.pair_config.accept_price(current_price, clock.unix_timestamp)?;
 
     // Calculate position size (notional value)
     let size = margin
         .checked_mul(leverage as u64)
         .ok_or(TradingError::ArithmeticOverflow)?;
 
+    // Per-pair cap and size-tiered leverage curve
+    ctx.accounts.pair_config.require_leverage(leverage, size, config.max_leverage)?;
+
+    // Skew-based price impact: the entry moves against trades that grow the pair's imbalance
+    let entry_price = ctx.accounts.pair_config.execution_price(
+        current_price,
+        PairConfig::trade_notional(dir, size, true),
+    )?;
+
     // Calculate trading fee
-    let trading_fee = calculate_trading_fee(size, config.trading_fee_bps)?;
+    // Scops NFT and volume tier discounts (the larger applies)
+    let scops_discount_bps = resolve_scops_discount_bps(
+        config,
+        &ctx.accounts.trader.key(),
+        ctx.accounts.scops_nft.as_ref(),
+        ctx.accounts.scops_mint.as_ref(),
+        ctx.accounts.scops_deposit.as_ref(),
+    )?;
+    let volume_discount_bps = VolumeTier::discount_for(
+        &config.volume_tiers,
+        ctx.accounts.trader_account.rolling_volume(clock.unix_timestamp),
+    );
+    let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+    let trading_fee = apply_fee_discount(config.fee_schedule.open_fee(size)?, discount_bps)?;
+    ctx.accounts.trader_account.record_volume(size, clock.unix_timestamp);
 
     // Total amount to transfer = margin + trading fee
     let total_transfer = margin
@@ -123,9 +203,10 @@ pub fn handler(
 
     // Calculate trigger prices for auto-deleverage
     let trigger_prices = calculate_trigger_prices(
-        current_price,
+        entry_price,
         dir,
-        &config.deleverage_thresholds,
+        fx_pair.quote_conversion() == QuoteConversion::InversePair,
+        &ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds),
     )?;
 
     // Initialize position
@@ -140,20 +221,55 @@ pub fn handler(
     position.margin = margin;
     position.initial_margin = margin;
     position.size = size;
-    position.entry_price = current_price;
+    position.entry_price = entry_price;
     position.entry_price_expo = price_expo;
     position.trigger_prices = trigger_prices;
     position.deleverage_executed = [false; 4];
+    position.trigger_crossed_at = [0; 4];
     position.opened_at = clock.unix_timestamp;
     position.closed_at = None;
     position.final_pnl_otus = None;
     position.bump = ctx.bumps.position;
+    position.version = Position::VERSION;
+    position.cross_margin = false;
+    position.entry_quote_price = quote_price.map_or(0, |(price, _)| price);
+    position.entry_quote_price_expo = quote_price.map_or(0, |(_, expo)| expo);
+    position.realized_pnl = 0;
+
+    ctx.accounts.pair_config.add_open_interest(dir, size)?;
 
-    // Increment position counter
+    // Increment position counter and open interest tracking
     let config = &mut ctx.accounts.config;
     config.position_counter = config.position_counter
         .checked_add(1)
         .ok_or(TradingError::ArithmeticOverflow)?;
+    config.open_position_count = config.open_position_count
+        .checked_add(1)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.total_open_margin = config.total_open_margin
+        .checked_add(margin)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    let (referral_reward, referral_rebate) = book_trading_fee(
+        config,
+        &mut ctx.accounts.trader_account,
+        ctx.accounts.referral_code.as_mut(),
+        trading_fee,
+    )?;
+
+    emit_cpi!(PositionOpened {
+        owner: position.owner,
+        position_id: position.position_id,
+        pair: fx_pair,
+        direction: dir,
+        leverage,
+        margin,
+        size,
+        entry_price,
+        entry_price_expo: price_expo,
+        trading_fee,
+        trigger_prices,
+        opened_at: clock.unix_timestamp,
+    });
 
     msg!("Position opened:");
     msg!("  ID: {}", position.position_id);
@@ -162,9 +278,28 @@ pub fn handler(
     msg!("  Margin: {} USDC", margin);
     msg!("  Leverage: {}x", leverage);
     msg!("  Size: {} USDC", size);
-    msg!("  Entry Price: {}", current_price);
+    msg!("  Oracle Price: {}", current_price);
+    msg!("  Entry Price: {}", entry_price);
     msg!("  Trading Fee: {} USDC", trading_fee);
+    msg!("  Referral Reward: {} USDC", referral_reward);
+    msg!("  Referral Rebate: {} USDC", referral_rebate);
     msg!("  Trigger Prices: {:?}", trigger_prices);
 
     Ok(())
 }
+
+#[event]
+pub struct PositionOpened {
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub pair: FxPair,
+    pub direction: Direction,
+    pub leverage: u8,
+    pub margin: u64,
+    pub size: u64,
+    pub entry_price: i64,
+    pub entry_price_expo: i32,
+    pub trading_fee: u64,
+    pub trigger_prices: [i64; 4],
+    pub opened_at: i64,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig};
+use crate::utils::{get_cached_price, get_quote_conversion_price, has_crossed_trigger, calculate_price_distance_bps, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct PositionHealth<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (bound oracle feeds)
+    #[account(
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Result of position_health
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct HealthQuote {
+    pub price: i64,
+    pub price_expo: i32,
+    pub unrealized_pnl: i64,
+    pub margin_health: u8,
+    /// Lowest deleverage tier not yet executed (None once all have run)
+    pub next_tier: Option<u8>,
+    pub next_trigger_price: Option<i64>,
+    /// Price move to the next trigger in bps of the current price (0 once crossed)
+    pub distance_to_trigger_bps: Option<u64>,
+}
+
+pub fn handler(
+    ctx: Context<PositionHealth>,
+) -> Result<HealthQuote> {
+    let config = &ctx.accounts.config;
+    let position = &ctx.accounts.position;
+
+    let (price, price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        position.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+
+    let unrealized_pnl = position.calculate_unrealized_pnl(price, price_expo, quote_price)?;
+    let margin_health = position.calculate_margin_health(price, price_expo, quote_price)?;
+
+    let next_tier = position.deleverage_executed
+        .iter()
+        .position(|&executed| !executed)
+        .map(|tier| tier as u8);
+    let next_trigger_price = next_tier.map(|tier| position.trigger_prices[tier as usize]);
+    let distance_to_trigger_bps = next_trigger_price.map(|trigger| {
+        if has_crossed_trigger(price, trigger, position.direction) {
+            0
+        } else {
+            calculate_price_distance_bps(price, trigger)
+        }
+    });
+
+    Ok(HealthQuote {
+        price,
+        price_expo,
+        unrealized_pnl,
+        margin_health,
+        next_tier,
+        next_trigger_price,
+        distance_to_trigger_bps,
+    })
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::TradingConfig;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ TradingError::InvalidAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Current authority (a multisig or governance PDA signs via CPI)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Proposing the default pubkey cancels a pending transfer
+    config.pending_authority = new_authority;
+
+    emit_cpi!(AuthorityProposed {
+        current_authority: config.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Authority transfer proposed: {} -> {}", config.authority, new_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
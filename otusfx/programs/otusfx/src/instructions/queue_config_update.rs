@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, ConfigProposal, ConfigUpdate, PairConfig};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ TradingError::InvalidAuthority
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ConfigProposal::LEN,
+        seeds = [
+            ConfigProposal::SEED_PREFIX,
+            &config.proposal_counter.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// Config of the pair a per-pair update targets (validated against the proposal)
+    pub pair_config: Option<Account<'info, PairConfig>>,
+
+    pub authority: Signer<'info>,
+
+    /// Pays rent for the proposal (refunded on execute/cancel)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<QueueConfigUpdate>,
+    update: ConfigUpdate,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    // Reject invalid changes now rather than after the delay
+    let mut preview = (**config).clone();
+    update.apply(&mut preview)?;
+    if let Some(pair_update) = &update.pair {
+        let pair_config = ctx.accounts.pair_config.as_ref().ok_or(TradingError::InvalidPairConfig)?;
+        let mut pair_preview = (**pair_config).clone();
+        pair_update.apply(&preview, &mut pair_preview)?;
+    }
+
+    let eta = clock.unix_timestamp
+        .checked_add(config.timelock_delay)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = config.proposal_counter;
+    proposal.update = update;
+    proposal.rent_payer = ctx.accounts.payer.key();
+    proposal.queued_at = clock.unix_timestamp;
+    proposal.eta = eta;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.version = ConfigProposal::VERSION;
+
+    config.proposal_counter = config.proposal_counter
+        .checked_add(1)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    emit_cpi!(ConfigUpdateQueued {
+        proposal_id: proposal.proposal_id,
+        update: proposal.update.clone(),
+        eta,
+    });
+
+    msg!("Config update queued:");
+    msg!("  Proposal ID: {}", proposal.proposal_id);
+    msg!("  ETA: {}", eta);
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdateQueued {
+    pub proposal_id: u64,
+    pub update: ConfigUpdate,
+    pub eta: i64,
+}
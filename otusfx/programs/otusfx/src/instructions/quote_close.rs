@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Mint};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, TraderAccount, CrossMarginAccount, VolumeTier};
+use crate::utils::{get_cached_price, get_quote_conversion_price, get_quote_settlement_price, usdc_to_otus, apply_settlement_haircut, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct QuoteClose<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (settlement price, price impact)
+    #[account(
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Pyth Oracle price cache account (unused once global settlement is active)
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Quote conversion pair's config, for its settlement price (cross pairs during settlement only)
+    pub quote_pair_config: Option<Account<'info, PairConfig>>,
+
+    /// Owner's cross-margin account (cross positions only; pays the close fee)
+    #[account(
+        seeds = [CrossMarginAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Option<Account<'info, CrossMarginAccount>>,
+
+    /// Trader's position index (optional; rolling volume for fee tiers)
+    #[account(
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Option<Account<'info, TraderAccount>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Result of quote_close, mirroring close_position (or settle_position during settlement)
+/// Cross positions mirror close_cross_position: the fee comes out of the account's collateral
+/// and nothing is paid out, as the margin returns to the account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CloseQuote {
+    pub exit_price: i64,
+    pub exit_price_expo: i32,
+    pub pnl_usdc: i64,
+    pub pnl_otus: i64,
+    pub equity: i64,
+    pub fee: u64,
+    pub payout: u64,
+}
+
+pub fn handler(
+    ctx: Context<QuoteClose>,
+) -> Result<CloseQuote> {
+    let config = &ctx.accounts.config;
+    let position = &ctx.accounts.position;
+    let pair_config = &ctx.accounts.pair_config;
+
+    let cross_collateral = if position.cross_margin {
+        let cross_margin = ctx.accounts.cross_margin.as_ref().ok_or(TradingError::InvalidCrossMarginPositions)?;
+        Some(cross_margin.collateral)
+    } else {
+        None
+    };
+
+    // During settlement positions exit fee-free at the frozen price with the pro-rata haircut
+    let (exit_price, exit_price_expo, quote_price, fee, payout) = if config.global_settlement {
+        require!(
+            pair_config.settlement_price > 0,
+            TradingError::SettlementPriceNotSet
+        );
+        let quote_price = get_quote_settlement_price(
+            position.pair,
+            ctx.accounts.quote_pair_config.as_deref(),
+        )?;
+        let payout = if cross_collateral.is_some() {
+            0
+        } else {
+            apply_settlement_haircut(position.margin, config.settlement_payout_bps)?
+        };
+        (pair_config.settlement_price, pair_config.settlement_price_expo, quote_price, 0, payout)
+    } else {
+        let (price, expo) = get_cached_price(
+            &ctx.accounts.price_cache,
+            position.pair,
+            MAX_STALENESS_SLOTS,
+            config.max_price_confidence_bps,
+            &ctx.accounts.clock,
+        )?;
+        let price = pair_config.execution_price(
+            price,
+            PairConfig::trade_notional(position.direction, position.size, false),
+        )?;
+        let quote_price = get_quote_conversion_price(
+            &ctx.accounts.pair_config,
+            ctx.accounts.quote_price_cache.as_deref(),
+            MAX_STALENESS_SLOTS,
+            config.max_price_confidence_bps,
+            &ctx.accounts.clock,
+        )?;
+        let scops_discount_bps = resolve_scops_discount_bps(
+            config,
+            &position.owner,
+            ctx.accounts.scops_nft.as_ref(),
+            ctx.accounts.scops_mint.as_ref(),
+            ctx.accounts.scops_deposit.as_ref(),
+        )?;
+        let volume_discount_bps = VolumeTier::discount_for(
+            &config.volume_tiers,
+            ctx.accounts.trader_account
+                .as_ref()
+                .map_or(0, |trader_account| trader_account.rolling_volume(ctx.accounts.clock.unix_timestamp)),
+        );
+        let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+        let fee = apply_fee_discount(config.fee_schedule.close_fee(position.size)?, discount_bps)?
+            .min(cross_collateral.unwrap_or(position.margin));
+        let payout = if cross_collateral.is_some() { 0 } else { position.margin - fee };
+        (price, expo, quote_price, fee, payout)
+    };
+
+    let pnl_usdc = position.calculate_unrealized_pnl(exit_price, exit_price_expo, quote_price)?;
+    let equity = (position.margin as i64)
+        .checked_add(pnl_usdc)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    // Same OTUS conversion as close_position
+    let otus_price_usdc = 1_000_000; // $1.00 in 6 decimals
+    let pnl_otus = if pnl_usdc >= 0 {
+        usdc_to_otus(pnl_usdc as u64, otus_price_usdc)? as i64
+    } else {
+        -(usdc_to_otus(pnl_usdc.unsigned_abs(), otus_price_usdc)? as i64)
+    };
+
+    Ok(CloseQuote {
+        exit_price,
+        exit_price_expo,
+        pnl_usdc,
+        pnl_otus,
+        equity,
+        fee,
+        payout,
+    })
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Mint};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig, TraderAccount, VolumeTier, FxPair, Direction, QuoteConversion};
+use crate::utils::{get_cached_price, calculate_trigger_prices, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct QuoteOpen<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Per-pair config (leverage limits, price impact)
+    #[account(
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Trader the quote is for
+    /// CHECK: Only compared against the Scops NFT proofs
+    pub trader: UncheckedAccount<'info>,
+
+    /// Trader's position index (optional; rolling volume for fee tiers)
+    #[account(
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Option<Account<'info, TraderAccount>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Result of quote_open, mirroring what open_position would record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OpenQuote {
+    pub size: u64,
+    pub trading_fee: u64,
+    pub total_transfer: u64,
+    pub entry_price: i64,
+    pub entry_price_expo: i32,
+    pub trigger_prices: [i64; 4],
+}
+
+pub fn handler(
+    ctx: Context<QuoteOpen>,
+    pair: u8,
+    direction: u8,
+    margin: u64,
+    leverage: u8,
+) -> Result<OpenQuote> {
+    let config = &ctx.accounts.config;
+
+    // Same validation as open_position
+    require!(
+        leverage >= config.min_leverage && leverage <= config.max_leverage,
+        TradingError::InvalidLeverage
+    );
+    let fx_pair = FxPair::from_u8(pair)
+        .ok_or(TradingError::InvalidPair)?;
+    let dir = Direction::from_u8(direction)
+        .ok_or(TradingError::InvalidDirection)?;
+    require!(margin > 0, TradingError::InsufficientMargin);
+    require!(ctx.accounts.pair_config.pair == fx_pair, TradingError::InvalidPair);
+
+    let (current_price, price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        fx_pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+
+    let size = margin
+        .checked_mul(leverage as u64)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    ctx.accounts.pair_config.require_leverage(leverage, size, config.max_leverage)?;
+
+    // Skew-based price impact: the entry moves against trades that grow the pair's imbalance
+    let entry_price = ctx.accounts.pair_config.execution_price(
+        current_price,
+        PairConfig::trade_notional(dir, size, true),
+    )?;
+
+    // Scops NFT and volume tier discounts (the larger applies)
+    let scops_discount_bps = resolve_scops_discount_bps(
+        config,
+        &ctx.accounts.trader.key(),
+        ctx.accounts.scops_nft.as_ref(),
+        ctx.accounts.scops_mint.as_ref(),
+        ctx.accounts.scops_deposit.as_ref(),
+    )?;
+    let volume_discount_bps = VolumeTier::discount_for(
+        &config.volume_tiers,
+        ctx.accounts.trader_account
+            .as_ref()
+            .map_or(0, |trader_account| trader_account.rolling_volume(ctx.accounts.clock.unix_timestamp)),
+    );
+    let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+    let trading_fee = apply_fee_discount(config.fee_schedule.open_fee(size)?, discount_bps)?;
+    let total_transfer = margin
+        .checked_add(trading_fee)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    let trigger_prices = calculate_trigger_prices(
+        entry_price,
+        dir,
+        fx_pair.quote_conversion() == QuoteConversion::InversePair,
+        &ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds),
+    )?;
+
+    Ok(OpenQuote {
+        size,
+        trading_fee,
+        total_transfer,
+        entry_price,
+        entry_price_expo: price_expo,
+        trigger_prices,
+    })
+}
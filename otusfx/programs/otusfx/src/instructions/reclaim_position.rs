@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::Position;
+
+#[derive(Accounts)]
+pub struct ReclaimPosition<'info> {
+    /// Closed position left behind before positions were closed on exit
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = !position.is_open @ TradingError::PositionStillOpen,
+        constraint = position.owner == owner.key() @ TradingError::UnauthorizedClose
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ReclaimPosition>,
+) -> Result<()> {
+    let position = &ctx.accounts.position;
+
+    msg!("Position reclaimed:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Closed At: {:?}", position.closed_at);
+    msg!("  PnL (OTUS): {:?}", position.final_pnl_otus);
+
+    Ok(())
+}
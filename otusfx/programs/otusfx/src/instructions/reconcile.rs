@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PauseFlags};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Anyone can run the solvency check
+    pub caller: Signer<'info>,
+
+    /// Protocol USDC vault
+    #[account(
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Protocol USD1 vault
+    #[account(
+        constraint = usd1_vault.key() == config.usd1_vault
+    )]
+    pub usd1_vault: Account<'info, TokenAccount>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<Reconcile>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = &ctx.accounts.clock;
+
+    let usdc_balance = ctx.accounts.usdc_vault.amount;
+    let usd1_balance = ctx.accounts.usd1_vault.amount;
+    let vault_balance = usdc_balance
+        .checked_add(usd1_balance)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    // Invariant: vault balances >= open margin + accrued fees + insurance fund + queued outflows
+    //            + unclaimed referral rewards and rebates + cross-margin collateral
+    //            + unclaimed margin refunds
+    let owed = config.total_open_margin
+        .checked_add(config.accrued_fees)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(config.insurance_fund)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(config.total_pending_outflow)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(config.total_referral_owed)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(config.total_cross_collateral)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(config.total_margin_owed)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    let shortfall = owed.saturating_sub(vault_balance);
+    let surplus = vault_balance.saturating_sub(owed);
+
+    // Halt every action if the vault is short beyond tolerance
+    let auto_paused = shortfall > config.reconcile_tolerance
+        && config.paused_actions != PauseFlags::ALL;
+    if auto_paused {
+        config.paused_actions = PauseFlags::ALL;
+    }
+
+    emit_cpi!(VaultReconciled {
+        usdc_vault_balance: usdc_balance,
+        usd1_vault_balance: usd1_balance,
+        total_open_margin: config.total_open_margin,
+        accrued_fees: config.accrued_fees,
+        insurance_fund: config.insurance_fund,
+        total_pending_outflow: config.total_pending_outflow,
+        total_referral_owed: config.total_referral_owed,
+        total_cross_collateral: config.total_cross_collateral,
+        total_margin_owed: config.total_margin_owed,
+        owed,
+        surplus,
+        shortfall,
+        tolerance: config.reconcile_tolerance,
+        auto_paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault reconciled:");
+    msg!("  Vault Balance: {} (USDC {} + USD1 {})", vault_balance, usdc_balance, usd1_balance);
+    msg!("  Open Margin: {}", config.total_open_margin);
+    msg!("  Accrued Fees: {}", config.accrued_fees);
+    msg!("  Insurance Fund: {}", config.insurance_fund);
+    msg!("  Pending Outflows: {}", config.total_pending_outflow);
+    msg!("  Referral Owed: {}", config.total_referral_owed);
+    msg!("  Cross Collateral: {}", config.total_cross_collateral);
+    msg!("  Margin Refunds Owed: {}", config.total_margin_owed);
+    msg!("  Owed: {}", owed);
+    msg!("  Surplus: {}", surplus);
+    msg!("  Shortfall: {}", shortfall);
+    msg!("  Auto-Paused: {}", auto_paused);
+
+    Ok(())
+}
+
+#[event]
+pub struct VaultReconciled {
+    pub usdc_vault_balance: u64,
+    pub usd1_vault_balance: u64,
+    pub total_open_margin: u64,
+    pub accrued_fees: u64,
+    pub insurance_fund: u64,
+    pub total_pending_outflow: u64,
+    pub total_referral_owed: u64,
+    pub total_cross_collateral: u64,
+    pub total_margin_owed: u64,
+    pub owed: u64,
+    pub surplus: u64,
+    pub shortfall: u64,
+    pub tolerance: u64,
+    pub auto_paused: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::ReferralCode;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(code: [u8; 16])]
+pub struct RegisterReferralCode<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = ReferralCode::LEN,
+        seeds = [ReferralCode::SEED_PREFIX, &code],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    /// Referrer credited with the fee share of traders bound to the code
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterReferralCode>,
+    code: [u8; 16],
+) -> Result<()> {
+    require!(code.iter().any(|&byte| byte != 0), TradingError::InvalidReferralCode);
+
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.code = code;
+    referral_code.referrer = ctx.accounts.referrer.key();
+    referral_code.referred_count = 0;
+    referral_code.claimable = 0;
+    referral_code.total_earned = 0;
+    referral_code.bump = ctx.bumps.referral_code;
+    referral_code.version = ReferralCode::VERSION;
+
+    emit_cpi!(ReferralCodeRegistered {
+        referral_code: referral_code.key(),
+        code,
+        referrer: referral_code.referrer,
+    });
+
+    msg!("Referral code registered:");
+    msg!("  Code: {}", referral_code.key());
+    msg!("  Referrer: {}", referral_code.referrer);
+
+    Ok(())
+}
+
+#[event]
+pub struct ReferralCodeRegistered {
+    pub referral_code: Pubkey,
+    pub code: [u8; 16],
+    pub referrer: Pubkey,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PendingOutflow};
+
+#[derive(Accounts)]
+pub struct ReleaseOutflow<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    /// Closed to the rent payer once fully released
+    #[account(
+        mut,
+        seeds = [PendingOutflow::SEED_PREFIX, &pending_outflow.outflow_id.to_le_bytes()],
+        bump = pending_outflow.bump
+    )]
+    pub pending_outflow: Account<'info, PendingOutflow>,
+
+    /// Anyone can release once the delay has passed
+    pub caller: Signer<'info>,
+
+    /// Token account recorded on the pending outflow
+    #[account(
+        mut,
+        constraint = recipient.key() == pending_outflow.recipient
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    /// Receives the pending outflow's rent once it is fully released
+    /// CHECK: Must match the pending outflow's rent payer
+    #[account(
+        mut,
+        constraint = rent_payer.key() == pending_outflow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseOutflow>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pending = &mut ctx.accounts.pending_outflow;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    let amount = config.release_outflow(pending.amount, pending.release_at, now)?;
+
+    let config_seeds = &[
+        TradingConfig::SEED_PREFIX,
+        &[config.bump],
+    ];
+    let signer_seeds = &[&config_seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.usdc_vault.to_account_info(),
+        to: ctx.accounts.recipient.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)?;
+
+    pending.amount = pending.amount
+        .checked_sub(amount)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+
+    msg!("Outflow released:");
+    msg!("  Outflow ID: {}", pending.outflow_id);
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Remaining: {} USDC", pending.amount);
+    msg!("  Recipient: {}", pending.recipient);
+
+    if pending.amount == 0 {
+        pending.close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags, TraderAccount, VolumeTier, QuoteConversion, ReferralCode};
+use crate::utils::{
+    get_cached_price, get_quote_conversion_price, calculate_trigger_prices,
+    calculate_blended_entry_price, apply_fee_discount, combine_fee_discounts, resolve_scops_discount_bps, book_trading_fee,
+    PYTH_ORACLE_PROGRAM_ID,
+};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct RestoreLeverage<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = !position.cross_margin @ TradingError::CrossMarginPosition,
+        constraint = position.owner == trader.key() @ TradingError::UnauthorizedClose
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (pause flags, leverage limits, volatility-scaled thresholds, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's position index (rolling volume for fee tiers)
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, trader.key().as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Position owner
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    /// Trader's USDC token account (pays the trading fee on added notional)
+    #[account(
+        mut,
+        constraint = trader_usdc.mint == usdc_vault.mint,
+        constraint = trader_usdc.owner == trader.key()
+    )]
+    pub trader_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Pyth Oracle price cache account
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Trader's Scops NFT token account (optional; with scops_mint and scops_deposit for a fee discount)
+    pub scops_nft: Option<Account<'info, TokenAccount>>,
+
+    /// Scops NFT mint issued by the bootstrap pool
+    pub scops_mint: Option<Account<'info, Mint>>,
+
+    /// Trader's bootstrap deposit recording the Scops tier
+    pub scops_deposit: Option<Account<'info, UserDeposit>>,
+
+    /// Referral code the trader is bound to (required once bound; credited a share of the fee)
+    #[account(mut)]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<RestoreLeverage>,
+    target_leverage: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let position = &mut ctx.accounts.position;
+    let clock = &ctx.accounts.clock;
+
+    // Increasing size is subject to the same pause as opening
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::OPEN,
+    )?;
+
+    // Can only grow back toward the leverage the position was opened with
+    require!(
+        target_leverage > position.leverage
+            && target_leverage <= position.initial_leverage
+            && target_leverage <= config.max_leverage,
+        TradingError::InvalidLeverage
+    );
+
+    // Get current price from Pyth Oracle cache
+    let (current_price, current_price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        position.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: reject bad ticks and pause during a cool-down
+    ctx.accounts.pair_config.accept_price(current_price, clock.unix_timestamp)?;
+
+    // Only a recovered position may re-lever
+    let margin_health = position.calculate_margin_health(current_price, current_price_expo, quote_price)?;
+    require!(
+        margin_health >= config.min_restore_health,
+        TradingError::MarginHealthTooLowToRestore
+    );
+
+    // Calculate added notional
+    let new_size = position.margin
+        .checked_mul(target_leverage as u64)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    let added_size = new_size
+        .checked_sub(position.size)
+        .ok_or(TradingError::InvalidLeverage)?;
+    require!(added_size > 0, TradingError::InvalidLeverage);
+
+    // Per-pair cap and size-tiered leverage curve apply to the grown notional
+    ctx.accounts.pair_config.require_leverage(target_leverage, new_size, config.max_leverage)?;
+
+    // Added notional executes with skew-based price impact
+    let execution_price = ctx.accounts.pair_config.execution_price(
+        current_price,
+        PairConfig::trade_notional(position.direction, added_size, true),
+    )?;
+
+    // Charge the open fee on added notional only
+    // Scops NFT and volume tier discounts (the larger applies)
+    let scops_discount_bps = resolve_scops_discount_bps(
+        config,
+        &ctx.accounts.trader.key(),
+        ctx.accounts.scops_nft.as_ref(),
+        ctx.accounts.scops_mint.as_ref(),
+        ctx.accounts.scops_deposit.as_ref(),
+    )?;
+    let volume_discount_bps = VolumeTier::discount_for(
+        &config.volume_tiers,
+        ctx.accounts.trader_account.rolling_volume(clock.unix_timestamp),
+    );
+    let discount_bps = combine_fee_discounts(scops_discount_bps, volume_discount_bps);
+    let trading_fee = apply_fee_discount(config.fee_schedule.open_fee(added_size)?, discount_bps)?;
+    ctx.accounts.trader_account.record_volume(added_size, clock.unix_timestamp);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.trader_usdc.to_account_info(),
+        to: ctx.accounts.usdc_vault.to_account_info(),
+        authority: ctx.accounts.trader.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, trading_fee)?;
+
+    // Express the execution price in the entry exponent before blending
+    let current_normalized = if current_price_expo == position.entry_price_expo {
+        execution_price
+    } else if position.entry_price_expo > current_price_expo {
+        let diff = (position.entry_price_expo - current_price_expo) as u32;
+        execution_price
+            .checked_div(10_i64.pow(diff))
+            .ok_or(TradingError::DivisionByZero)?
+    } else {
+        let diff = (current_price_expo - position.entry_price_expo) as u32;
+        execution_price
+            .checked_mul(10_i64.pow(diff))
+            .ok_or(TradingError::ArithmeticOverflow)?
+    };
+
+    let blended_entry = calculate_blended_entry_price(
+        position.size,
+        position.entry_price,
+        added_size,
+        current_normalized,
+    )?;
+
+    // Update position state
+    ctx.accounts.pair_config.add_open_interest(position.direction, added_size)?;
+    position.size = new_size;
+    position.leverage = target_leverage;
+    position.entry_price = blended_entry;
+    position.trigger_prices = calculate_trigger_prices(
+        blended_entry,
+        position.direction,
+        position.pair.quote_conversion() == QuoteConversion::InversePair,
+        &ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds),
+    )?;
+
+    // Re-arm every executed tier the position has recovered above
+    let thresholds = ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds);
+    for (tier, &threshold) in thresholds.iter().enumerate() {
+        if position.deleverage_executed[tier] && margin_health > threshold {
+            position.deleverage_executed[tier] = false;
+            position.trigger_crossed_at[tier] = 0;
+        }
+    }
+
+    let (referral_reward, referral_rebate) = book_trading_fee(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.trader_account,
+        ctx.accounts.referral_code.as_mut(),
+        trading_fee,
+    )?;
+
+    msg!("Leverage restored:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Margin Health: {}%", margin_health);
+    msg!("  New Leverage: {}x", target_leverage);
+    msg!("  Added Size: {} USDC", added_size);
+    msg!("  New Size: {} USDC", new_size);
+    msg!("  Blended Entry Price: {}", blended_entry);
+    msg!("  Trading Fee: {} USDC", trading_fee);
+    msg!("  Referral Reward: {} USDC", referral_reward);
+    msg!("  Referral Rebate: {} USDC", referral_rebate);
+    msg!("  Trigger Prices: {:?}", position.trigger_prices);
+
+    Ok(())
+}
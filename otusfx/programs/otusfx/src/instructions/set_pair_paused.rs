@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig, PauseFlags};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPairPaused<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.guardian == guardian.key() @ TradingError::InvalidGuardian
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPairPaused>,
+    paused_actions: u8,
+) -> Result<()> {
+    require!(
+        paused_actions & !PauseFlags::ALL == 0,
+        TradingError::InvalidPauseFlags
+    );
+
+    let pair_config = &mut ctx.accounts.pair_config;
+    pair_config.paused_actions = paused_actions;
+
+    emit_cpi!(PairPauseUpdated {
+        guardian: ctx.accounts.guardian.key(),
+        pair: pair_config.pair as u8,
+        paused_actions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("{:?} paused actions: {:#06b}", pair_config.pair, paused_actions);
+
+    Ok(())
+}
+
+#[event]
+pub struct PairPauseUpdated {
+    pub guardian: Pubkey,
+    pub pair: u8,
+    pub paused_actions: u8,
+    pub timestamp: i64,
+}
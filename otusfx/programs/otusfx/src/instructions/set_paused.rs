@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PauseFlags};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.guardian == guardian.key() @ TradingError::InvalidGuardian
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPaused>,
+    paused_actions: u8,
+) -> Result<()> {
+    require!(
+        paused_actions & !PauseFlags::ALL == 0,
+        TradingError::InvalidPauseFlags
+    );
+
+    let config = &mut ctx.accounts.config;
+
+    // Settlement keeps every action paused permanently
+    require!(!config.global_settlement, TradingError::GlobalSettlementActive);
+
+    config.paused_actions = paused_actions;
+
+    emit_cpi!(PauseUpdated {
+        guardian: ctx.accounts.guardian.key(),
+        paused_actions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Global paused actions: {:#06b}", paused_actions);
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub guardian: Pubkey,
+    pub paused_actions: u8,
+    pub timestamp: i64,
+}
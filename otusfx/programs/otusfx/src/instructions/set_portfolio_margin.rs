@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, CrossMarginAccount};
+use crate::utils::{mark_cross_positions, cross_margin_health};
+
+/// Switch a cross-margin account between allocated and portfolio (net currency exposure) margin
+///
+/// Remaining accounts: (position, pair config, price cache[, quote price cache]) for every open
+/// cross position, used to check the account is healthy under the new mode.
+#[derive(Accounts)]
+pub struct SetPortfolioMargin<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, owner.key().as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<SetPortfolioMargin>,
+    enabled: bool,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let cross_margin = &mut ctx.accounts.cross_margin;
+
+    if enabled {
+        require!(
+            config.currency_risk_weights_bps.iter().all(|&bps| bps > 0),
+            TradingError::PortfolioMarginUnavailable
+        );
+    }
+    cross_margin.portfolio_margin = enabled;
+
+    let marks = mark_cross_positions(
+        cross_margin,
+        ctx.remaining_accounts,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+    let health = cross_margin_health(cross_margin, &marks, config)?;
+    require!(health >= config.min_cross_health, TradingError::CrossMarginHealthTooLow);
+
+    msg!("Portfolio margin updated:");
+    msg!("  Owner: {}", cross_margin.owner);
+    msg!("  Enabled: {}", enabled);
+    msg!("  Health: {}%", health);
+
+    Ok(())
+}
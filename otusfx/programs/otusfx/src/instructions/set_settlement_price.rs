@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig};
+use super::SettlementPriceSet;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSettlementPrice<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ TradingError::InvalidAuthority,
+        constraint = config.global_settlement @ TradingError::GlobalSettlementNotActive
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetSettlementPrice>,
+    price: i64,
+    price_expo: i32,
+) -> Result<()> {
+    let pair_config = &mut ctx.accounts.pair_config;
+    pair_config.set_settlement_price(price, price_expo)?;
+
+    emit_cpi!(SettlementPriceSet {
+        pair: pair_config.pair as u8,
+        price,
+        price_expo,
+        from_oracle: false,
+    });
+
+    msg!("Settlement price set by governance:");
+    msg!("  Pair: {:?}", pair_config.pair);
+    msg!("  Price: {} (expo {})", price, price_expo);
+
+    Ok(())
+}
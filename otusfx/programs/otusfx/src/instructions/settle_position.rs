@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, PendingOutflow, TraderAccount, CloseReason};
+use crate::instructions::PositionClosed;
+use crate::utils::{get_quote_settlement_price, usdc_to_otus, apply_settlement_haircut, route_usdc_outflow};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettlePosition<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump,
+        constraint = config.global_settlement @ TradingError::GlobalSettlementNotActive
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = !position.cross_margin @ TradingError::CrossMarginPosition
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (settlement price, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Quote conversion pair's config, for its settlement price (cross pairs during settlement only)
+    #[account(mut)]
+    pub quote_pair_config: Option<Account<'info, PairConfig>>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Position owner (receives the position account's rent)
+    /// CHECK: Must match the position owner
+    #[account(
+        mut,
+        address = position.owner @ TradingError::UnauthorizedClose
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Owner or any keeper can settle
+    #[account(mut)]
+    pub settler: Signer<'info>,
+
+    /// Position owner's USDC token account (receives the settled margin)
+    #[account(
+        mut,
+        constraint = owner_usdc.mint == usdc_vault.mint,
+        constraint = owner_usdc.owner == position.owner @ TradingError::UnauthorizedClose
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Provide to queue the payout for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = settler,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<SettlePosition>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pair_config = &ctx.accounts.pair_config;
+    let position = &mut ctx.accounts.position;
+    let clock = &ctx.accounts.clock;
+
+    require!(
+        pair_config.settlement_price > 0,
+        TradingError::SettlementPriceNotSet
+    );
+
+    // PnL at the frozen settlement price (and the quote conversion pair's, for crosses)
+    let quote_price = get_quote_settlement_price(
+        position.pair,
+        ctx.accounts.quote_pair_config.as_deref(),
+    )?;
+    let settled_pnl = position.calculate_unrealized_pnl(
+        pair_config.settlement_price,
+        pair_config.settlement_price_expo,
+        quote_price,
+    )?;
+
+    // Settlement in OTUS, as in close_position
+    let otus_price_usdc = 1_000_000; // $1.00 in 6 decimals
+    let final_pnl_otus = if settled_pnl >= 0 {
+        usdc_to_otus(settled_pnl as u64, otus_price_usdc)? as i64
+    } else {
+        -(usdc_to_otus(settled_pnl.unsigned_abs(), otus_price_usdc)? as i64)
+    };
+
+    // Every margin claim takes the same pro-rata haircut
+    let payout = apply_settlement_haircut(position.margin, config.settlement_payout_bps)?;
+
+    let queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.owner_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.settler.key(),
+        payout,
+        clock.unix_timestamp,
+    )?;
+
+    // The position account is closed on exit; its final record lives on in the event and history
+    // Realized PnL includes any earlier deleverage tiers
+    let lifetime_pnl = position.lifetime_pnl(settled_pnl)?;
+    let record = PositionClosed {
+        owner: position.owner,
+        position_id: position.position_id,
+        pair: position.pair,
+        direction: position.direction,
+        reason: CloseReason::Settlement,
+        initial_leverage: position.initial_leverage,
+        leverage: position.leverage,
+        initial_margin: position.initial_margin,
+        margin: position.margin,
+        size: position.size,
+        entry_price: position.entry_price,
+        entry_price_expo: position.entry_price_expo,
+        exit_price: pair_config.settlement_price,
+        exit_price_expo: pair_config.settlement_price_expo,
+        realized_pnl: lifetime_pnl,
+        final_pnl_otus,
+        fee: 0,
+        margin_returned: payout,
+        deleverage_executed: position.deleverage_executed,
+        opened_at: position.opened_at,
+        closed_at: clock.unix_timestamp,
+    };
+    ctx.accounts.trader_account.record_close(
+        position.position_id,
+        &record.try_to_vec()?,
+        lifetime_pnl,
+    )?;
+    emit_cpi!(record);
+
+    let config = &mut ctx.accounts.config;
+    config.open_position_count = config.open_position_count
+        .checked_sub(1)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    config.total_open_margin = config.total_open_margin
+        .saturating_sub(position.margin);
+
+    msg!("Position settled:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Settlement Price: {}", pair_config.settlement_price);
+    msg!("  PnL (USDC): {}", settled_pnl);
+    msg!("  PnL (OTUS): {}", final_pnl_otus);
+    msg!("  Margin: {} USDC", position.margin);
+    msg!("  Payout: {} USDC ({} bps)", payout, config.settlement_payout_bps);
+    msg!("  Payout Queued: {}", queued);
+    msg!("  Open Positions Remaining: {}", config.open_position_count);
+
+    ctx.accounts.pair_config.remove_open_interest(position.direction, position.size);
+
+    // Every later settlement in the pair (and in crosses quoted through it) uses the same prices
+    ctx.accounts.pair_config.settlement_price_frozen = true;
+    if quote_price.is_some() {
+        if let Some(quote_pair_config) = ctx.accounts.quote_pair_config.as_mut() {
+            quote_pair_config.settlement_price_frozen = true;
+        }
+    }
+
+    Ok(())
+}
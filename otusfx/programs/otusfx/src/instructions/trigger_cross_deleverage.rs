@@ -0,0 +1,391 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags, PendingOutflow, TraderAccount, CrossMarginAccount, CloseReason};
+use crate::instructions::{PositionClosed, DeleverageExecuted};
+use crate::utils::{
+    get_cached_price, get_quote_conversion_price, calculate_keeper_reward, split_keeper_reward, route_usdc_outflow,
+    mark_cross_positions, cross_margin_health, is_largest_risk, PYTH_ORACLE_PROGRAM_ID,
+};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+/// Deleverage a cross-margin account whose health fell to a tier threshold
+///
+/// The position reduced must be the largest by notional among those not yet deleveraged
+/// at this tier, so the biggest risks are cut first. Remaining accounts: (position, pair config,
+/// price cache[, quote price cache]) for every open cross position of the account, the target included.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TriggerCrossDeleverage<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            Position::SEED_PREFIX,
+            position.owner.as_ref(),
+            &position.position_id.to_le_bytes()
+        ],
+        bump = position.bump,
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = position.cross_margin @ TradingError::InvalidCrossMarginPositions
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Per-pair config (pause flags, volatility-scaled thresholds, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Cross-margin account whose health is checked
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    /// Position owner (receives the position account's rent when tier 3 closes it)
+    /// CHECK: Must match the position owner
+    #[account(
+        mut,
+        address = position.owner @ TradingError::UnauthorizedClose
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Keeper who is triggering the deleverage (can be anyone)
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Keeper's USDC token account (receives reward)
+    #[account(
+        mut,
+        constraint = keeper_usdc.mint == usdc_vault.mint,
+        constraint = keeper_usdc.owner == keeper.key()
+    )]
+    pub keeper_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Pyth Oracle price cache account for the target position's pair
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Provide to queue the keeper reward for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = keeper,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<TriggerCrossDeleverage>,
+    tier: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let position = &mut ctx.accounts.position;
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    let clock = &ctx.accounts.clock;
+
+    // Validate deleverage is not paused globally or for this pair
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::DELEVERAGE,
+    )?;
+
+    // Validate tier
+    require!(tier < 4, TradingError::InvalidDeleverageTier);
+
+    // Check if tier already executed
+    require!(
+        !position.deleverage_executed[tier as usize],
+        TradingError::DeleverageTierAlreadyExecuted
+    );
+
+    // Account health across every cross position must be at or below the tier threshold
+    let marks = mark_cross_positions(
+        cross_margin,
+        ctx.remaining_accounts,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+    let margin_health = cross_margin_health(cross_margin, &marks, config)?;
+    let threshold = ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds)[tier as usize];
+    require!(
+        margin_health <= threshold,
+        TradingError::MarginHealthAboveThreshold
+    );
+
+    // Largest-risk positions are reduced first
+    require!(
+        is_largest_risk(&marks, position.position_id, tier),
+        TradingError::NotLargestRiskPosition
+    );
+
+    // Get current price from Pyth Oracle cache
+    let (current_price, current_price_expo) = get_cached_price(
+        &ctx.accounts.price_cache,
+        position.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: reject bad ticks and pause during a cool-down
+    ctx.accounts.pair_config.accept_price(current_price, clock.unix_timestamp)?;
+
+    // Calculate deleverage amount
+    let (close_percentage, new_leverage) = position.calculate_deleverage_amount(tier)?;
+
+    // Calculate size to close and the margin allocation it releases
+    let (close_size, released_margin) = if close_percentage == 100 {
+        (position.size, position.margin)
+    } else {
+        (
+            position.size
+                .checked_mul(close_percentage)
+                .ok_or(TradingError::ArithmeticOverflow)?
+                .checked_div(100)
+                .ok_or(TradingError::DivisionByZero)?,
+            position.margin
+                .checked_mul(close_percentage)
+                .ok_or(TradingError::ArithmeticOverflow)?
+                .checked_div(100)
+                .ok_or(TradingError::DivisionByZero)?,
+        )
+    };
+
+    let collateral_before = cross_margin.collateral;
+
+    // Calculate PnL on closed portion
+    let total_unrealized_pnl = position.calculate_unrealized_pnl(current_price, current_price_expo, quote_price)?;
+    let closed_pnl = if close_percentage == 100 {
+        total_unrealized_pnl
+    } else {
+        (total_unrealized_pnl as i128)
+            .checked_mul(close_percentage as i128)
+            .ok_or(TradingError::ArithmeticOverflow)?
+            .checked_div(100)
+            .ok_or(TradingError::DivisionByZero)? as i64
+    };
+
+    // Update position state; the closed PnL is realized into the shared collateral
+    position.size = position.size
+        .checked_sub(close_size)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    // Forced reductions execute at the oracle price but still unwind open interest
+    ctx.accounts.pair_config.remove_open_interest(position.direction, close_size);
+    position.margin = position.margin
+        .checked_sub(released_margin)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    position.leverage = new_leverage;
+    position.deleverage_executed[tier as usize] = true;
+    position.realize_pnl(closed_pnl)?;
+
+    cross_margin.realize_pnl(closed_pnl)?;
+    cross_margin.allocated_margin = cross_margin.allocated_margin.saturating_sub(released_margin);
+
+    // Cross positions have no trigger crossing to run a Dutch auction from; the capped rate applies
+    let keeper_fee_bps = config.keeper_fee_bps;
+    let keeper_reward = calculate_keeper_reward(
+        close_size,
+        keeper_fee_bps,
+        config.min_keeper_reward[tier as usize],
+        config.max_keeper_reward[tier as usize],
+    )?;
+
+    // Charge the reward to the account's collateral; any shortfall comes from the insurance fund
+    let (from_margin, from_insurance) = split_keeper_reward(
+        keeper_reward,
+        cross_margin.collateral,
+        config.insurance_fund,
+    );
+    let keeper_reward = from_margin
+        .checked_add(from_insurance)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    cross_margin.collateral = cross_margin.collateral
+        .checked_sub(from_margin)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+
+    // Deleverage fee on the closed notional, plus the liquidation penalty on the final tier
+    // Both are capped by the remaining collateral; the fee is kept as accrued fees and the
+    // penalty goes to the insurance fund, as for isolated positions
+    let deleverage_fee = config.fee_schedule
+        .deleverage_fee(close_size)?
+        .min(cross_margin.collateral);
+    let liquidation_penalty = if tier == 3 {
+        config.fee_schedule
+            .liquidation_penalty(close_size)?
+            .min(cross_margin.collateral - deleverage_fee)
+    } else {
+        0
+    };
+    let fees_charged = deleverage_fee + liquidation_penalty;
+    cross_margin.collateral -= fees_charged;
+
+    // Transfer keeper reward from vault (queued if over the outflow limit)
+    let reward_queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.keeper_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.keeper.key(),
+        keeper_reward,
+        clock.unix_timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.insurance_fund = config.insurance_fund
+        .checked_sub(from_insurance)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    config.accrued_fees = config.accrued_fees
+        .checked_add(deleverage_fee)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.insurance_fund = config.insurance_fund
+        .checked_add(liquidation_penalty)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    // Keep aggregate collateral claims in line with the account's new collateral
+    config.total_cross_collateral = config.total_cross_collateral
+        .saturating_sub(collateral_before)
+        .saturating_add(cross_margin.collateral);
+
+    // Close position completely if this was tier 3 (final deleverage)
+    if tier == 3 {
+        position.is_open = false;
+        position.closed_at = Some(clock.unix_timestamp);
+        cross_margin.remove_position(position.position_id, position.margin);
+
+        config.open_position_count = config.open_position_count
+            .checked_sub(1)
+            .ok_or(TradingError::ArithmeticUnderflow)?;
+
+        let record = PositionClosed {
+            owner: position.owner,
+            position_id: position.position_id,
+            pair: position.pair,
+            direction: position.direction,
+            reason: CloseReason::Deleverage,
+            initial_leverage: position.initial_leverage,
+            leverage: position.leverage,
+            initial_margin: position.initial_margin,
+            margin: position.margin,
+            size: close_size,
+            entry_price: position.entry_price,
+            entry_price_expo: position.entry_price_expo,
+            exit_price: current_price,
+            exit_price_expo: current_price_expo,
+            realized_pnl: position.realized_pnl,
+            final_pnl_otus: position.final_pnl_otus.unwrap_or(0),
+            fee: fees_charged,
+            margin_returned: 0,
+            deleverage_executed: position.deleverage_executed,
+            opened_at: position.opened_at,
+            closed_at: clock.unix_timestamp,
+        };
+        ctx.accounts.trader_account.record_close(
+            position.position_id,
+            &record.try_to_vec()?,
+            position.realized_pnl,
+        )?;
+        emit_cpi!(record);
+    }
+
+    emit_cpi!(DeleverageExecuted {
+        owner: position.owner,
+        position_id: position.position_id,
+        tier,
+        keeper: ctx.accounts.keeper.key(),
+        price: current_price,
+        price_expo: current_price_expo,
+        margin_health,
+        closed_size: close_size,
+        closed_pnl,
+        new_leverage,
+        remaining_size: position.size,
+        remaining_margin: position.margin,
+        keeper_fee_bps,
+        keeper_reward,
+        from_margin,
+        from_insurance,
+        reward_queued,
+        deleverage_fee,
+        liquidation_penalty,
+        // Remaining margin stays in the account's collateral
+        margin_refund: 0,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Cross deleverage triggered:");
+    msg!("  Position ID: {}", position.position_id);
+    msg!("  Tier: {}", tier);
+    msg!("  Current Price: {}", current_price);
+    msg!("  Account Health: {}%", margin_health);
+    msg!("  Closed Size: {} USDC", close_size);
+    msg!("  Closed PnL: {} USDC", closed_pnl);
+    msg!("  New Leverage: {}x", new_leverage);
+    msg!("  Remaining Size: {} USDC", position.size);
+    msg!("  Collateral: {} USDC", cross_margin.collateral);
+    msg!("  Keeper Reward: {} USDC", keeper_reward);
+    msg!("  Charged to Collateral: {} USDC", from_margin);
+    msg!("  Charged to Insurance: {} USDC", from_insurance);
+    msg!("  Reward Queued: {}", reward_queued);
+    msg!("  Deleverage Fee: {} USDC", deleverage_fee);
+    msg!("  Liquidation Penalty: {} USDC", liquidation_penalty);
+
+    // A fully deleveraged position has nothing left to manage; refund its rent to the owner
+    if tier == 3 {
+        ctx.accounts.position.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
 use crate::error::TradingError;
-use crate::state::{TradingConfig, Position};
-use crate::utils::{get_cached_price, has_crossed_trigger, calculate_keeper_reward, PYTH_ORACLE_PROGRAM_ID};
+use crate::state::{TradingConfig, Position, PairConfig, PauseFlags, PendingOutflow, TraderAccount, CloseReason};
+use crate::instructions::PositionClosed;
+use crate::utils::{
+    get_cached_price, get_quote_conversion_price, has_crossed_trigger, calculate_auction_fee_bps,
+    calculate_keeper_reward, split_keeper_reward, split_liquidation_margin, route_usdc_outflow, PYTH_ORACLE_PROGRAM_ID,
+};
 
 /// Maximum staleness in slots (~5 minutes at 400ms/slot)
 const MAX_STALENESS_SLOTS: u64 = 750;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct TriggerDeleverage<'info> {
     #[account(
+        mut,
         seeds = [TradingConfig::SEED_PREFIX],
         bump = config.bump
     )]
@@ -23,10 +29,35 @@ pub struct TriggerDeleverage<'info> {
             &position.position_id.to_le_bytes()
         ],
         bump = position.bump,
-        constraint = position.is_open @ TradingError::PositionNotOpen
+        constraint = position.is_open @ TradingError::PositionNotOpen,
+        constraint = !position.cross_margin @ TradingError::CrossMarginPosition
     )]
     pub position: Account<'info, Position>,
 
+    /// Per-pair config (pause flags, volatility-scaled thresholds, open interest)
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[position.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Owner's open position index
+    #[account(
+        mut,
+        seeds = [TraderAccount::SEED_PREFIX, position.owner.as_ref()],
+        bump = trader_account.bump
+    )]
+    pub trader_account: Account<'info, TraderAccount>,
+
+    /// Position owner (receives the position account's rent when tier 3 closes it)
+    /// CHECK: Must match the position owner
+    #[account(
+        mut,
+        address = position.owner @ TradingError::UnauthorizedClose
+    )]
+    pub owner: UncheckedAccount<'info>,
+
     /// Keeper who is triggering the deleverage (can be anyone)
     #[account(mut)]
     pub keeper: Signer<'info>,
@@ -47,13 +78,29 @@ pub struct TriggerDeleverage<'info> {
     pub usdc_vault: Account<'info, TokenAccount>,
 
     /// Pyth Oracle price cache account
-    /// CHECK: Validated by owner check in get_cached_price
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
     #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
         constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
     )]
     pub price_cache: AccountInfo<'info>,
 
+    /// Quote conversion oracle price cache (cross pairs only: GBPUSD for EURGBP, USDJPY for JPY crosses)
+    /// CHECK: Must be the pair's bound quote feed; validated in get_quote_conversion_price
+    pub quote_price_cache: Option<UncheckedAccount<'info>>,
+
+    /// Provide to queue the keeper reward for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = keeper,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
@@ -65,6 +112,13 @@ pub fn handler(
     let position = &mut ctx.accounts.position;
     let clock = &ctx.accounts.clock;
 
+    // Validate deleverage is not paused globally or for this pair
+    PauseFlags::require_active(
+        config.paused_actions,
+        ctx.accounts.pair_config.paused_actions,
+        PauseFlags::DELEVERAGE,
+    )?;
+
     // Validate tier
     require!(tier < 4, TradingError::InvalidDeleverageTier);
 
@@ -82,19 +136,32 @@ pub fn handler(
         config.max_price_confidence_bps,
         clock,
     )?;
+    let quote_price = get_quote_conversion_price(
+        &ctx.accounts.pair_config,
+        ctx.accounts.quote_price_cache.as_deref(),
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+
+    // Circuit breaker: reject bad ticks and pause during a cool-down
+    ctx.accounts.pair_config.accept_price(current_price, clock.unix_timestamp)?;
+
+    // Deleverage thresholds scale up with the pair's volatility
+    let thresholds = ctx.accounts.pair_config.deleverage_thresholds(&config.deleverage_thresholds);
 
     // Verify trigger price has been crossed
-    let trigger_price = position.trigger_prices[tier as usize];
+    let trigger_price = position.effective_trigger_price(tier, &thresholds)?;
     require!(
         has_crossed_trigger(current_price, trigger_price, position.direction),
         TradingError::MarginHealthAboveThreshold
     );
 
     // Calculate margin health
-    let margin_health = position.calculate_margin_health(current_price, current_price_expo)?;
+    let margin_health = position.calculate_margin_health(current_price, current_price_expo, quote_price)?;
 
     // Verify margin health is at or below threshold
-    let threshold = config.deleverage_thresholds[tier as usize];
+    let threshold = thresholds[tier as usize];
     require!(
         margin_health <= threshold,
         TradingError::MarginHealthAboveThreshold
@@ -114,8 +181,10 @@ pub fn handler(
             .ok_or(TradingError::DivisionByZero)?
     };
 
+    let margin_before = position.margin;
+
     // Calculate PnL on closed portion
-    let total_unrealized_pnl = position.calculate_unrealized_pnl(current_price, current_price_expo)?;
+    let total_unrealized_pnl = position.calculate_unrealized_pnl(current_price, current_price_expo, quote_price)?;
     let closed_pnl = if close_percentage == 100 {
         total_unrealized_pnl
     } else {
@@ -130,6 +199,8 @@ pub fn handler(
     position.size = position.size
         .checked_sub(close_size)
         .ok_or(TradingError::ArithmeticUnderflow)?;
+    // Forced reductions execute at the oracle price but still unwind open interest
+    ctx.accounts.pair_config.remove_open_interest(position.direction, close_size);
 
     // Adjust margin based on PnL
     if closed_pnl >= 0 {
@@ -142,37 +213,167 @@ pub fn handler(
             .ok_or(TradingError::ArithmeticUnderflow)?;
     }
 
+    position.realize_pnl(closed_pnl)?;
+
     // Update leverage
     position.leverage = new_leverage;
 
     // Mark tier as executed
     position.deleverage_executed[tier as usize] = true;
 
-    // Calculate keeper reward (0.05% of closed notional, not margin)
-    let keeper_reward = calculate_keeper_reward(close_size, config.keeper_fee_bps)?;
+    // Dutch-auction keeper fee: grows the longer the trigger has stayed crossed
+    let crossed_at = position.trigger_crossed_at[tier as usize];
+    let elapsed = if crossed_at > 0 { clock.unix_timestamp - crossed_at } else { 0 };
+    let keeper_fee_bps = calculate_auction_fee_bps(
+        config.keeper_fee_start_bps,
+        config.keeper_fee_bps,
+        config.keeper_fee_ramp_secs,
+        elapsed,
+    );
+
+    // Calculate keeper reward (share of closed notional, not margin), bounded per tier
+    let keeper_reward = calculate_keeper_reward(
+        close_size,
+        keeper_fee_bps,
+        config.min_keeper_reward[tier as usize],
+        config.max_keeper_reward[tier as usize],
+    )?;
 
-    // Transfer keeper reward from vault
-    let config_seeds = &[
-        TradingConfig::SEED_PREFIX,
-        &[config.bump],
-    ];
-    let signer_seeds = &[&config_seeds[..]];
+    // Charge the reward to the deleveraged position; any shortfall comes from the insurance fund
+    let (from_margin, from_insurance) = split_keeper_reward(
+        keeper_reward,
+        position.margin,
+        config.insurance_fund,
+    );
+    let keeper_reward = from_margin
+        .checked_add(from_insurance)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    position.margin = position.margin
+        .checked_sub(from_margin)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
 
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.usdc_vault.to_account_info(),
-        to: ctx.accounts.keeper_usdc.to_account_info(),
-        authority: config.to_account_info(),
+    // Deleverage fee on the closed notional, capped by the remaining margin and kept as accrued fees
+    let deleverage_fee = config.fee_schedule
+        .deleverage_fee(close_size)?
+        .min(position.margin);
+    position.margin -= deleverage_fee;
+
+    // The final tier also charges the liquidation penalty, which goes to the insurance fund;
+    // whatever margin is left after it still belongs to the owner
+    let liquidation_penalty = if tier == 3 {
+        let penalty = config.fee_schedule.liquidation_penalty(close_size)?;
+        split_liquidation_margin(position.margin, penalty).0
+    } else {
+        0
     };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, keeper_reward)?;
+    position.margin -= liquidation_penalty;
+    let fees_charged = deleverage_fee + liquidation_penalty;
+
+    // Transfer keeper reward from vault (queued if over the outflow limit)
+    let reward_queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.keeper_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.keeper.key(),
+        keeper_reward,
+        clock.unix_timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.insurance_fund = config.insurance_fund
+        .checked_sub(from_insurance)
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    config.accrued_fees = config.accrued_fees
+        .checked_add(deleverage_fee)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.insurance_fund = config.insurance_fund
+        .checked_add(liquidation_penalty)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    // Keep aggregate margin claims in line with the position's new margin
+    config.total_open_margin = config.total_open_margin
+        .saturating_sub(margin_before)
+        .saturating_add(position.margin);
 
     // Close position completely if this was tier 3 (final deleverage)
+    // Margin left after the liquidation is credited to the owner as a claim rather than
+    // paid out, so the owner cannot block the final tier by closing their token account
+    let margin_refund = if tier == 3 { position.margin } else { 0 };
     if tier == 3 {
         position.is_open = false;
         position.closed_at = Some(clock.unix_timestamp);
+
+        config.open_position_count = config.open_position_count
+            .checked_sub(1)
+            .ok_or(TradingError::ArithmeticUnderflow)?;
+        config.total_open_margin = config.total_open_margin
+            .saturating_sub(margin_refund);
+        config.total_margin_owed = config.total_margin_owed
+            .checked_add(margin_refund)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        ctx.accounts.trader_account.claimable_margin = ctx.accounts.trader_account.claimable_margin
+            .checked_add(margin_refund)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        position.margin = 0;
+
+        let record = PositionClosed {
+            owner: position.owner,
+            position_id: position.position_id,
+            pair: position.pair,
+            direction: position.direction,
+            reason: CloseReason::Deleverage,
+            initial_leverage: position.initial_leverage,
+            leverage: position.leverage,
+            initial_margin: position.initial_margin,
+            margin: margin_refund,
+            size: close_size,
+            entry_price: position.entry_price,
+            entry_price_expo: position.entry_price_expo,
+            exit_price: current_price,
+            exit_price_expo: current_price_expo,
+            realized_pnl: position.realized_pnl,
+            final_pnl_otus: position.final_pnl_otus.unwrap_or(0),
+            fee: fees_charged,
+            margin_returned: margin_refund,
+            deleverage_executed: position.deleverage_executed,
+            opened_at: position.opened_at,
+            closed_at: clock.unix_timestamp,
+        };
+        ctx.accounts.trader_account.record_close(
+            position.position_id,
+            &record.try_to_vec()?,
+            position.realized_pnl,
+        )?;
+        emit_cpi!(record);
     }
 
+    emit_cpi!(DeleverageExecuted {
+        owner: position.owner,
+        position_id: position.position_id,
+        tier,
+        keeper: ctx.accounts.keeper.key(),
+        price: current_price,
+        price_expo: current_price_expo,
+        margin_health,
+        closed_size: close_size,
+        closed_pnl,
+        new_leverage,
+        remaining_size: position.size,
+        remaining_margin: position.margin,
+        keeper_fee_bps,
+        keeper_reward,
+        from_margin,
+        from_insurance,
+        reward_queued,
+        deleverage_fee,
+        liquidation_penalty,
+        margin_refund,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Deleverage triggered:");
     msg!("  Position ID: {}", position.position_id);
     msg!("  Tier: {}", tier);
@@ -183,7 +384,44 @@ pub fn handler(
     msg!("  Closed PnL: {} USDC", closed_pnl);
     msg!("  New Leverage: {}x", new_leverage);
     msg!("  Remaining Size: {} USDC", position.size);
+    msg!("  Keeper Fee: {} bps ({}s after crossing)", keeper_fee_bps, elapsed);
     msg!("  Keeper Reward: {} USDC", keeper_reward);
+    msg!("  Charged to Margin: {} USDC", from_margin);
+    msg!("  Charged to Insurance: {} USDC", from_insurance);
+    msg!("  Reward Queued: {}", reward_queued);
+    msg!("  Deleverage Fee: {} USDC", deleverage_fee);
+    msg!("  Liquidation Penalty: {} USDC", liquidation_penalty);
+    msg!("  Margin Refund: {} USDC", margin_refund);
+
+    // A fully deleveraged position has nothing left to manage; refund its rent to the owner
+    if tier == 3 {
+        ctx.accounts.position.close(ctx.accounts.owner.to_account_info())?;
+    }
 
     Ok(())
 }
+
+#[event]
+pub struct DeleverageExecuted {
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub tier: u8,
+    pub keeper: Pubkey,
+    pub price: i64,
+    pub price_expo: i32,
+    pub margin_health: u8,
+    pub closed_size: u64,
+    pub closed_pnl: i64,
+    pub new_leverage: u8,
+    pub remaining_size: u64,
+    pub remaining_margin: u64,
+    pub keeper_fee_bps: u16,
+    pub keeper_reward: u64,
+    pub from_margin: u64,
+    pub from_insurance: u64,
+    pub reward_queued: bool,
+    pub deleverage_fee: u64,
+    pub liquidation_penalty: u64,
+    pub margin_refund: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig};
+use crate::utils::{get_cached_pyth_price, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[derive(Accounts)]
+pub struct UpdatePairVolatility<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Anyone can sample volatility (typically a keeper)
+    pub keeper: Signer<'info>,
+
+    /// Pyth Oracle price cache account for the pair
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<UpdatePairVolatility>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pair_config = &mut ctx.accounts.pair_config;
+
+    let pyth_price = get_cached_pyth_price(
+        &ctx.accounts.price_cache,
+        pair_config.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+
+    let sampled = pair_config.record_volatility_sample(
+        pyth_price.price,
+        pyth_price.conf,
+        pyth_price.publish_time,
+    )?;
+    require!(sampled, TradingError::VolatilitySampleTooSoon);
+
+    msg!("Pair volatility updated:");
+    msg!("  Pair: {:?}", pair_config.pair);
+    msg!("  Price: {} (conf {})", pyth_price.price, pyth_price.conf);
+    msg!("  Volatility: {} bps", pair_config.volatility_bps);
+    msg!("  Leverage Scale: {} bps", pair_config.leverage_scale_bps());
+    msg!("  Deleverage Thresholds: {:?}", pair_config.deleverage_thresholds(&config.deleverage_thresholds));
+
+    Ok(())
+}
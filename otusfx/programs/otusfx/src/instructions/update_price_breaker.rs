@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PairConfig, FxPair};
+use crate::utils::{get_cached_price, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePriceBreaker<'info> {
+    #[account(
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [PairConfig::SEED_PREFIX, &[pair_config.pair as u8]],
+        bump = pair_config.bump
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+
+    /// Anyone can screen a price (typically a keeper, ahead of a bad tick reaching deleverage)
+    pub keeper: Signer<'info>,
+
+    /// Pyth Oracle price cache account for the pair
+    /// CHECK: Must be the pair's bound feed; validated by owner check in get_cached_price
+    #[account(
+        constraint = price_cache.key() == pair_config.price_feed @ TradingError::InvalidOracleAccount,
+        constraint = price_cache.owner == &PYTH_ORACLE_PROGRAM_ID @ TradingError::InvalidOracleAccount
+    )]
+    pub price_cache: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<UpdatePriceBreaker>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pair_config = &mut ctx.accounts.pair_config;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    let (price, _) = get_cached_price(
+        &ctx.accounts.price_cache,
+        pair_config.pair,
+        MAX_STALENESS_SLOTS,
+        config.max_price_confidence_bps,
+        &ctx.accounts.clock,
+    )?;
+
+    // A trip succeeds so the cool-down persists; instructions reading the price fail until it ends
+    let last_accepted_price = pair_config.last_accepted_price;
+    let accepted = pair_config.screen_price(price, now)?;
+
+    if !accepted {
+        emit_cpi!(CircuitBreakerTripped {
+            pair: pair_config.pair,
+            price,
+            last_accepted_price,
+            cooldown_until: pair_config.breaker_tripped_until,
+        });
+    }
+
+    msg!("Price breaker updated:");
+    msg!("  Pair: {:?}", pair_config.pair);
+    msg!("  Price: {}", price);
+    msg!("  Accepted: {}", accepted);
+    msg!("  Last Accepted Price: {}", pair_config.last_accepted_price);
+    msg!("  Cool-down Until: {}", pair_config.breaker_tripped_until);
+
+    Ok(())
+}
+
+/// A pair's price moved too far too fast; opens and deleverage pause until the cool-down ends
+#[event]
+pub struct CircuitBreakerTripped {
+    pub pair: FxPair,
+    pub price: i64,
+    pub last_accepted_price: i64,
+    pub cooldown_until: i64,
+}
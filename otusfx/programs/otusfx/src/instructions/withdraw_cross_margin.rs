@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, CrossMarginAccount, PendingOutflow};
+use crate::utils::{route_usdc_outflow, apply_settlement_haircut, mark_cross_positions, cross_margin_health};
+
+/// Withdraw free collateral from a cross-margin account
+///
+/// Remaining accounts: (position, pair config, price cache[, quote price cache]) for every open
+/// cross position, used to check the account stays healthy after the withdrawal.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawCrossMargin<'info> {
+    #[account(
+        mut,
+        seeds = [TradingConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, TradingConfig>,
+
+    #[account(
+        mut,
+        seeds = [CrossMarginAccount::SEED_PREFIX, owner.key().as_ref()],
+        bump = cross_margin.bump
+    )]
+    pub cross_margin: Account<'info, CrossMarginAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Owner's USDC token account (receives the collateral)
+    #[account(
+        mut,
+        constraint = owner_usdc.mint == usdc_vault.mint,
+        constraint = owner_usdc.owner == owner.key()
+    )]
+    pub owner_usdc: Account<'info, TokenAccount>,
+
+    /// Protocol USDC vault
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == config.usdc_vault
+    )]
+    pub usdc_vault: Account<'info, TokenAccount>,
+
+    /// Provide to queue the withdrawal for delayed release when it exceeds the outflow limit
+    #[account(
+        init,
+        payer = owner,
+        space = PendingOutflow::LEN,
+        seeds = [PendingOutflow::SEED_PREFIX, &config.outflow_counter.to_le_bytes()],
+        bump
+    )]
+    pub pending_outflow: Option<Account<'info, PendingOutflow>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawCrossMargin>,
+    amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let clock = &ctx.accounts.clock;
+
+    require!(amount > 0, TradingError::InvalidAmount);
+
+    let payout = if config.global_settlement {
+        // Positions are closed at the settlement price first; collateral takes the pro-rata haircut
+        require!(
+            ctx.accounts.cross_margin.position_ids.is_empty(),
+            TradingError::CrossPositionsStillOpen
+        );
+        apply_settlement_haircut(amount, config.settlement_payout_bps)?
+    } else {
+        // Withdrawals are outflows: held while payouts are held
+        config.require_payouts_active()?;
+        amount
+    };
+
+    let cross_margin = &mut ctx.accounts.cross_margin;
+    let available = if cross_margin.portfolio_margin {
+        cross_margin.collateral
+    } else {
+        cross_margin.free_collateral()
+    };
+    require!(amount <= available, TradingError::InsufficientCrossCollateral);
+    cross_margin.collateral -= amount;
+
+    // The remaining collateral must still cover the positions at the restore threshold
+    let marks = mark_cross_positions(
+        cross_margin,
+        ctx.remaining_accounts,
+        config.max_price_confidence_bps,
+        clock,
+    )?;
+    let health = cross_margin_health(cross_margin, &marks, config)?;
+    require!(health >= config.min_cross_health, TradingError::CrossMarginHealthTooLow);
+
+    let queued = route_usdc_outflow(
+        &mut ctx.accounts.config,
+        ctx.accounts.pending_outflow.as_mut().zip(ctx.bumps.pending_outflow),
+        &ctx.accounts.usdc_vault,
+        &ctx.accounts.owner_usdc,
+        &ctx.accounts.token_program,
+        ctx.accounts.owner.key(),
+        payout,
+        clock.unix_timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.total_cross_collateral = config.total_cross_collateral
+        .saturating_sub(amount);
+
+    let cross_margin = &ctx.accounts.cross_margin;
+    emit_cpi!(CrossMarginWithdrawn {
+        owner: cross_margin.owner,
+        amount,
+        payout,
+        queued,
+        collateral: cross_margin.collateral,
+        health,
+    });
+
+    msg!("Cross-margin withdrawal:");
+    msg!("  Owner: {}", cross_margin.owner);
+    msg!("  Amount: {} USDC", amount);
+    msg!("  Payout: {} USDC", payout);
+    msg!("  Queued: {}", queued);
+    msg!("  Collateral: {} USDC", cross_margin.collateral);
+    msg!("  Health: {}%", health);
+
+    Ok(())
+}
+
+#[event]
+pub struct CrossMarginWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub payout: u64,
+    pub queued: bool,
+    pub collateral: u64,
+    pub health: u8,
+}
@@ -8,6 +8,7 @@ pub mod state;
 pub mod utils;
 
 use instructions::*;
+use state::ConfigUpdate;
 
 #[program]
 pub mod otusfx {
@@ -43,6 +44,22 @@ pub mod otusfx {
         instructions::trigger_deleverage::handler(ctx, tier)
     }
 
+    /// Record when a deleverage trigger was crossed (starts the keeper reward auction)
+    pub fn mark_trigger_crossed(
+        ctx: Context<MarkTriggerCrossed>,
+        tier: u8,
+    ) -> Result<()> {
+        instructions::mark_trigger_crossed::handler(ctx, tier)
+    }
+
+    /// Increase a recovered position's size back toward its initial leverage
+    pub fn restore_leverage(
+        ctx: Context<RestoreLeverage>,
+        target_leverage: u8,
+    ) -> Result<()> {
+        instructions::restore_leverage::handler(ctx, target_leverage)
+    }
+
     /// Close a position manually or after full deleverage
     pub fn close_position(
         ctx: Context<ClosePosition>,
@@ -50,14 +67,286 @@ pub mod otusfx {
         instructions::close_position::handler(ctx)
     }
 
-    /// Update trading configuration (admin only)
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        new_trading_fee_bps: Option<u16>,
-        new_keeper_fee_bps: Option<u16>,
-        new_max_leverage: Option<u8>,
-        is_paused: Option<bool>,
+    /// Reclaim rent from a position closed before position accounts were closed on exit (owner only)
+    pub fn reclaim_position(
+        ctx: Context<ReclaimPosition>,
+    ) -> Result<()> {
+        instructions::reclaim_position::handler(ctx)
+    }
+
+    /// Quote size, fee and trigger prices for opening a position (read-only, for simulation)
+    pub fn quote_open(
+        ctx: Context<QuoteOpen>,
+        pair: u8,
+        direction: u8,
+        margin: u64,
+        leverage: u8,
+    ) -> Result<OpenQuote> {
+        instructions::quote_open::handler(ctx, pair, direction, margin, leverage)
+    }
+
+    /// Quote PnL, fee and payout for closing a position (read-only, for simulation)
+    pub fn quote_close(
+        ctx: Context<QuoteClose>,
+    ) -> Result<CloseQuote> {
+        instructions::quote_close::handler(ctx)
+    }
+
+    /// Report margin health and distance to the next deleverage trigger (read-only, for simulation)
+    pub fn position_health(
+        ctx: Context<PositionHealth>,
+    ) -> Result<HealthQuote> {
+        instructions::position_health::handler(ctx)
+    }
+
+    /// Queue a timelocked config change (admin only)
+    pub fn queue_config_update(
+        ctx: Context<QueueConfigUpdate>,
+        update: ConfigUpdate,
+    ) -> Result<()> {
+        instructions::queue_config_update::handler(ctx, update)
+    }
+
+    /// Apply a queued config change once its timelock has elapsed (permissionless)
+    pub fn execute_config_update(
+        ctx: Context<ExecuteConfigUpdate>,
+    ) -> Result<()> {
+        instructions::execute_config_update::handler(ctx)
+    }
+
+    /// Cancel a queued config change (admin only)
+    pub fn cancel_config_update(
+        ctx: Context<CancelConfigUpdate>,
+    ) -> Result<()> {
+        instructions::cancel_config_update::handler(ctx)
+    }
+
+    /// Create the per-pair config for an FX pair, bound to its Pyth feeds (admin only)
+    /// `quote_price_feed` is the conversion pair's feed for cross pairs (unused otherwise)
+    pub fn initialize_pair(
+        ctx: Context<InitializePair>,
+        pair: u8,
+        price_feed: Pubkey,
+        quote_price_feed: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_pair::handler(ctx, pair, price_feed, quote_price_feed)
+    }
+
+    /// Create a trader's open position index (permissionless, required before opening)
+    pub fn initialize_trader_account(
+        ctx: Context<InitializeTraderAccount>,
+    ) -> Result<()> {
+        instructions::initialize_trader_account::handler(ctx)
+    }
+
+    /// Set the globally paused actions bitmask immediately (guardian only)
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        paused_actions: u8,
+    ) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused_actions)
+    }
+
+    /// Set the paused actions bitmask for a single pair immediately (guardian only)
+    pub fn set_pair_paused(
+        ctx: Context<SetPairPaused>,
+        paused_actions: u8,
+    ) -> Result<()> {
+        instructions::set_pair_paused::handler(ctx, paused_actions)
+    }
+
+    /// Enter one-way global settlement, fixing the pro-rata margin payout (authority or guardian)
+    pub fn enter_global_settlement(
+        ctx: Context<EnterGlobalSettlement>,
+    ) -> Result<()> {
+        instructions::enter_global_settlement::handler(ctx)
+    }
+
+    /// Freeze a pair's settlement price from the last valid oracle price (permissionless)
+    pub fn capture_settlement_price(
+        ctx: Context<CaptureSettlementPrice>,
+    ) -> Result<()> {
+        instructions::capture_settlement_price::handler(ctx)
+    }
+
+    /// Set or override a pair's settlement price (admin only)
+    pub fn set_settlement_price(
+        ctx: Context<SetSettlementPrice>,
+        price: i64,
+        price_expo: i32,
+    ) -> Result<()> {
+        instructions::set_settlement_price::handler(ctx, price, price_expo)
+    }
+
+    /// Close a position at its pair's settlement price (owner or keeper)
+    pub fn settle_position(
+        ctx: Context<SettlePosition>,
+    ) -> Result<()> {
+        instructions::settle_position::handler(ctx)
+    }
+
+    /// Check vault balances cover open margin, fees and insurance; auto-pause on shortfall (permissionless)
+    pub fn reconcile(
+        ctx: Context<Reconcile>,
+    ) -> Result<()> {
+        instructions::reconcile::handler(ctx)
+    }
+
+    /// Release a rate-limited outflow once its delay has passed, up to the limiter's capacity (permissionless)
+    pub fn release_outflow(
+        ctx: Context<ReleaseOutflow>,
+    ) -> Result<()> {
+        instructions::release_outflow::handler(ctx)
+    }
+
+    /// Approve a rate-limited outflow for release without waiting out its delay (guardian only)
+    pub fn approve_outflow(
+        ctx: Context<ApproveOutflow>,
+    ) -> Result<()> {
+        instructions::approve_outflow::handler(ctx)
+    }
+
+    /// Top up the insurance fund that backs keeper reward shortfalls
+    pub fn fund_insurance(
+        ctx: Context<FundInsurance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_insurance::handler(ctx, amount)
+    }
+
+    /// Propose a new config authority (step 1 of 2, current authority only)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed config authority (step 2 of 2, pending authority only)
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
+    ) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Grow a config to the current layout version (admin only)
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+    ) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
+    /// Grow a position to the current layout version (permissionless)
+    pub fn migrate_position(
+        ctx: Context<MigratePosition>,
+    ) -> Result<()> {
+        instructions::migrate_position::handler(ctx)
+    }
+
+    /// Register a referral code earning a share of referred traders' fees (permissionless)
+    pub fn register_referral_code(
+        ctx: Context<RegisterReferralCode>,
+        code: [u8; 16],
+    ) -> Result<()> {
+        instructions::register_referral_code::handler(ctx, code)
+    }
+
+    /// Bind a trader account to a referral code (once, trader only)
+    pub fn bind_referral_code(
+        ctx: Context<BindReferralCode>,
+    ) -> Result<()> {
+        instructions::bind_referral_code::handler(ctx)
+    }
+
+    /// Withdraw a referral code's accrued fee share (referrer only)
+    pub fn claim_referral_rewards(
+        ctx: Context<ClaimReferralRewards>,
+    ) -> Result<()> {
+        instructions::claim_referral_rewards::handler(ctx)
+    }
+
+    /// Withdraw a referred trader's accrued fee rebate (trader only)
+    pub fn claim_referral_rebate(
+        ctx: Context<ClaimReferralRebate>,
+    ) -> Result<()> {
+        instructions::claim_referral_rebate::handler(ctx)
+    }
+
+    /// Withdraw margin left on positions closed by the final deleverage tier (trader only)
+    pub fn claim_margin_refund(
+        ctx: Context<ClaimMarginRefund>,
+    ) -> Result<()> {
+        instructions::claim_margin_refund::handler(ctx)
+    }
+
+    /// Create a trader's cross-margin account (opt-in, owner only)
+    pub fn initialize_cross_margin(
+        ctx: Context<InitializeCrossMargin>,
+    ) -> Result<()> {
+        instructions::initialize_cross_margin::handler(ctx)
+    }
+
+    /// Deposit USDC collateral into a cross-margin account
+    pub fn deposit_cross_margin(
+        ctx: Context<DepositCrossMargin>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_cross_margin::handler(ctx, amount)
+    }
+
+    /// Withdraw free collateral while the account stays healthy (owner only)
+    pub fn withdraw_cross_margin(
+        ctx: Context<WithdrawCrossMargin>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_cross_margin::handler(ctx, amount)
+    }
+
+    /// Open a position with margin allocated from the trader's cross-margin account
+    pub fn open_cross_position(
+        ctx: Context<OpenCrossPosition>,
+        pair: u8,
+        direction: u8,
+        margin: u64,
+        leverage: u8,
+    ) -> Result<()> {
+        instructions::open_cross_position::handler(ctx, pair, direction, margin, leverage)
+    }
+
+    /// Close a cross position, releasing its margin to the account (owner only)
+    pub fn close_cross_position(
+        ctx: Context<CloseCrossPosition>,
+    ) -> Result<()> {
+        instructions::close_cross_position::handler(ctx)
+    }
+
+    /// Deleverage an unhealthy cross-margin account, largest position first (permissionless)
+    pub fn trigger_cross_deleverage(
+        ctx: Context<TriggerCrossDeleverage>,
+        tier: u8,
+    ) -> Result<()> {
+        instructions::trigger_cross_deleverage::handler(ctx, tier)
+    }
+
+    /// Switch a cross-margin account to or from portfolio margin on net currency exposure (owner only)
+    pub fn set_portfolio_margin(
+        ctx: Context<SetPortfolioMargin>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_portfolio_margin::handler(ctx, enabled)
+    }
+
+    /// Fold the pair's latest oracle price and confidence into its volatility estimate (permissionless)
+    pub fn update_pair_volatility(
+        ctx: Context<UpdatePairVolatility>,
+    ) -> Result<()> {
+        instructions::update_pair_volatility::handler(ctx)
+    }
+
+    /// Screen the pair's oracle price, tripping the circuit breaker on an abnormal move (permissionless)
+    pub fn update_price_breaker(
+        ctx: Context<UpdatePriceBreaker>,
     ) -> Result<()> {
-        instructions::update_config::handler(ctx, new_trading_fee_bps, new_keeper_fee_bps, new_max_leverage, is_paused)
+        instructions::update_price_breaker::handler(ctx)
     }
 }
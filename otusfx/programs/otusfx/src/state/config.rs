@@ -1,12 +1,26 @@
 use anchor_lang::prelude::*;
 use anchor_lang::{AnchorSerialize, AnchorDeserialize};
+use crate::error::TradingError;
+use super::{OutflowLimiter, FeeSchedule, VolumeTier, PauseFlags};
 
 /// Global trading configuration
 #[account]
 pub struct TradingConfig {
-    /// Authority that can update config
+    /// Authority that can update config (may be a multisig / governance PDA)
     pub authority: Pubkey,
 
+    /// Proposed new authority awaiting acceptance (default pubkey if none)
+    pub pending_authority: Pubkey,
+
+    /// Guardian that can pause actions globally or per pair (distinct from authority)
+    pub guardian: Pubkey,
+
+    /// Delay in seconds before a queued config change can execute
+    pub timelock_delay: i64,
+
+    /// Counter for config proposal PDAs
+    pub proposal_counter: u64,
+
     /// OTUS treasury program for settlement
     pub otus_treasury: Pubkey,
 
@@ -16,12 +30,19 @@ pub struct TradingConfig {
     /// USD1 vault for margin deposits
     pub usd1_vault: Pubkey,
 
-    /// Trading fee in basis points (8 bps = 0.08%)
+    /// Legacy open fee in basis points (superseded by fee_schedule in v3, kept for layout)
     pub trading_fee_bps: u16,
 
-    /// Keeper fee in basis points (5 bps = 0.05% of closed notional)
+    /// Maximum keeper fee in basis points (5 bps = 0.05% of closed notional)
+    /// Cap of the Dutch-auction keeper reward curve
     pub keeper_fee_bps: u16,
 
+    /// Keeper fee when a trigger is first crossed (start of the Dutch auction)
+    pub keeper_fee_start_bps: u16,
+
+    /// Seconds for the keeper fee to grow linearly from start to cap
+    pub keeper_fee_ramp_secs: i64,
+
     /// Maximum leverage allowed (default: 20x)
     pub max_leverage: u8,
 
@@ -31,6 +52,9 @@ pub struct TradingConfig {
     /// Margin health thresholds for auto-deleverage [50, 35, 25, 15]
     pub deleverage_thresholds: [u8; 4],
 
+    /// Minimum margin health required to restore leverage after deleverage (default: 80)
+    pub min_restore_health: u8,
+
     /// Pyth program ID
     pub pyth_program: Pubkey,
 
@@ -40,35 +64,232 @@ pub struct TradingConfig {
     /// Maximum price confidence ratio (e.g., 2% = 200 bps)
     pub max_price_confidence_bps: u16,
 
-    /// Trading paused flag
-    pub is_paused: bool,
+    /// Actions paused across every pair (PauseFlags bitmask)
+    pub paused_actions: u8,
 
     /// Global position counter
     pub position_counter: u64,
 
+    /// Insurance fund balance held in the USDC vault (covers keeper reward shortfalls)
+    pub insurance_fund: u64,
+
+    /// Trading fees collected into the USDC vault and owed to the protocol
+    pub accrued_fees: u64,
+
+    /// Vault shortfall (USDC, 6 decimals) tolerated by reconcile before auto-pausing
+    pub reconcile_tolerance: u64,
+
+    /// Rate limiter on outflows from the USDC vault
+    pub usdc_outflow_limiter: OutflowLimiter,
+
+    /// Delay in seconds before an outflow queued over the limit can be released
+    pub outflow_release_delay: i64,
+
+    /// Counter for pending outflow PDAs
+    pub outflow_counter: u64,
+
+    /// Sum of queued outflows still held in the vault
+    pub total_pending_outflow: u64,
+
+    /// Minimum keeper reward per deleverage tier (USDC, 6 decimals)
+    pub min_keeper_reward: [u64; 4],
+
+    /// Maximum keeper reward per deleverage tier (USDC, 6 decimals)
+    pub max_keeper_reward: [u64; 4],
+
+    /// Number of currently open positions
+    pub open_position_count: u64,
+
+    /// Sum of margin held by open positions (USDC claims on the vault)
+    pub total_open_margin: u64,
+
+    /// One-way global settlement flag (set once, never cleared)
+    pub global_settlement: bool,
+
+    /// Timestamp global settlement began (0 if not settled)
+    pub settled_at: i64,
+
+    /// Margin payout ratio fixed at settlement (10_000 = no haircut)
+    pub settlement_payout_bps: u16,
+
     /// Bump for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Maximum open positions per trader (bounded by TraderAccount::MAX_OPEN_POSITIONS)
+    pub max_open_positions_per_trader: u8,
+
+    /// Open, close, deleverage and liquidation fee rates
+    pub fee_schedule: FeeSchedule,
+
+    /// Fee discount in basis points per Scops NFT tier [None, Bronze, Silver, Gold, Platinum]
+    /// Zero (no discount) until set by a config update
+    pub scops_discount_bps: [u16; 5],
+
+    /// Fee discount ladder by rolling 30-day trader volume (all disabled until set)
+    pub volume_tiers: [VolumeTier; 4],
+
+    /// Share of every trading fee credited to the trader's referrer, in basis points
+    pub referral_share_bps: u16,
+
+    /// Share of every trading fee rebated to a referred trader, in basis points
+    pub referral_rebate_bps: u16,
+
+    /// Referral rewards and trader rebates credited but not yet claimed (USDC claims on the vault)
+    pub total_referral_owed: u64,
+
+    /// Sum of collateral held by cross-margin accounts (USDC claims on the vault)
+    pub total_cross_collateral: u64,
+
+    /// Portfolio-margin risk weight per currency in basis points of net exposure, indexed by Currency
+    /// All zero (portfolio margin unavailable) until set by a config update; the USD weight is
+    /// unused since collateral is USD
+    pub currency_risk_weights_bps: [u16; 8],
+
+    /// Margin from fully deleveraged positions credited to owners but not yet claimed
+    /// (USDC claims on the vault)
+    pub total_margin_owed: u64,
+
+    /// Minimum portfolio-margin requirement in basis points of gross notional
+    /// Keeps a fully offset book from carrying no margin at all
+    pub portfolio_margin_floor_bps: u16,
+
+    /// Minimum cross-margin account health after opening, closing or withdrawing (default: 80)
+    pub min_cross_health: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 22],
 }
 
 impl TradingConfig {
     pub const LEN: usize = 8 +  // discriminator
         32 +                     // authority
+        32 +                     // pending_authority
+        32 +                     // guardian
+        8 +                      // timelock_delay
+        8 +                      // proposal_counter
         32 +                     // otus_treasury
         32 +                     // usdc_vault
         32 +                     // usd1_vault
         2 +                      // trading_fee_bps
         2 +                      // keeper_fee_bps
+        2 +                      // keeper_fee_start_bps
+        8 +                      // keeper_fee_ramp_secs
         1 +                      // max_leverage
         1 +                      // min_leverage
         4 +                      // deleverage_thresholds
+        1 +                      // min_restore_health
         32 +                     // pyth_program
         8 +                      // max_price_age
         2 +                      // max_price_confidence_bps
-        1 +                      // is_paused
+        1 +                      // paused_actions
         8 +                      // position_counter
-        1;                       // bump
+        8 +                      // insurance_fund
+        8 +                      // accrued_fees
+        8 +                      // reconcile_tolerance
+        OutflowLimiter::LEN +    // usdc_outflow_limiter
+        8 +                      // outflow_release_delay
+        8 +                      // outflow_counter
+        8 +                      // total_pending_outflow
+        32 +                     // min_keeper_reward (4 * 8)
+        32 +                     // max_keeper_reward (4 * 8)
+        8 +                      // open_position_count
+        8 +                      // total_open_margin
+        1 +                      // global_settlement
+        8 +                      // settled_at
+        2 +                      // settlement_payout_bps
+        1 +                      // bump
+        1 +                      // version
+        1 +                      // max_open_positions_per_trader
+        FeeSchedule::LEN +       // fee_schedule
+        10 +                     // scops_discount_bps (5 * 2)
+        4 * VolumeTier::LEN +    // volume_tiers
+        2 +                      // referral_share_bps
+        2 +                      // referral_rebate_bps
+        8 +                      // total_referral_owed
+        8 +                      // total_cross_collateral
+        16 +                     // currency_risk_weights_bps (8 * 2)
+        8 +                      // total_margin_owed
+        2 +                      // portfolio_margin_floor_bps
+        1 +                      // min_cross_health
+        22;                      // reserved
 
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Default per-trader open position limit
+    pub const DEFAULT_MAX_OPEN_POSITIONS: u8 = 10;
+
+    /// Require that payouts (queued outflow releases, claims, collateral withdrawals) are not held
+    ///
+    /// The guardian holds payouts by pausing closes globally. Global settlement pauses every
+    /// action and the pause can no longer be lifted, so payouts are never held once settled.
+    pub fn require_payouts_active(&self) -> Result<()> {
+        if self.global_settlement {
+            return Ok(());
+        }
+
+        PauseFlags::require_active(self.paused_actions, 0, PauseFlags::CLOSE)
+    }
+
+    /// Vault balance left to back margin claims at global settlement
+    ///
+    /// Accrued fees, the insurance fund, queued outflows and unclaimed referral rewards and
+    /// margin refunds are owed in full, so only the rest is shared pro-rata between margin claims.
+    pub fn settlement_backing(&self, vault_balance: u64) -> Result<u64> {
+        let other_liabilities = self.accrued_fees
+            .checked_add(self.insurance_fund)
+            .ok_or(TradingError::ArithmeticOverflow)?
+            .checked_add(self.total_pending_outflow)
+            .ok_or(TradingError::ArithmeticOverflow)?
+            .checked_add(self.total_referral_owed)
+            .ok_or(TradingError::ArithmeticOverflow)?
+            .checked_add(self.total_margin_owed)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+
+        Ok(vault_balance.saturating_sub(other_liabilities))
+    }
+
+    /// Book an outflow that exceeded the limiter for delayed release
+    /// Returns the outflow ID and the time it becomes releasable.
+    pub fn queue_outflow(&mut self, amount: u64, now: i64) -> Result<(u64, i64)> {
+        let outflow_id = self.outflow_counter;
+        let release_at = now
+            .checked_add(self.outflow_release_delay)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+
+        self.outflow_counter = self.outflow_counter
+            .checked_add(1)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        self.total_pending_outflow = self.total_pending_outflow
+            .checked_add(amount)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+
+        Ok((outflow_id, release_at))
+    }
+
+    /// Release as much of a queued outflow as the limiter allows
+    ///
+    /// Released outflows still draw on the limiter, so a backlog drains at most one
+    /// bucket per window; whatever does not fit stays queued for a later release.
+    /// Returns the amount released.
+    pub fn release_outflow(&mut self, pending_amount: u64, release_at: i64, now: i64) -> Result<u64> {
+        self.require_payouts_active()?;
+        require!(now >= release_at, TradingError::OutflowNotReleasable);
+
+        let amount = self.usdc_outflow_limiter.consume_available(pending_amount, now);
+        require!(amount > 0, TradingError::OutflowRateLimited);
+
+        self.total_pending_outflow = self.total_pending_outflow
+            .checked_sub(amount)
+            .ok_or(TradingError::ArithmeticUnderflow)?;
+
+        Ok(amount)
+    }
 }
 
 /// FX trading pairs supported by the protocol
@@ -104,6 +325,67 @@ impl FxPair {
             _ => None,
         }
     }
+
+    /// Base and quote currencies of the pair
+    pub fn currencies(&self) -> (Currency, Currency) {
+        match self {
+            FxPair::EURUSD => (Currency::EUR, Currency::USD),
+            FxPair::GBPUSD => (Currency::GBP, Currency::USD),
+            FxPair::USDJPY => (Currency::USD, Currency::JPY),
+            FxPair::AUDUSD => (Currency::AUD, Currency::USD),
+            FxPair::USDCAD => (Currency::USD, Currency::CAD),
+            FxPair::USDCHF => (Currency::USD, Currency::CHF),
+            FxPair::NZDUSD => (Currency::NZD, Currency::USD),
+            FxPair::EURGBP => (Currency::EUR, Currency::GBP),
+            FxPair::EURJPY => (Currency::EUR, Currency::JPY),
+            FxPair::GBPJPY => (Currency::GBP, Currency::JPY),
+            FxPair::AUDJPY => (Currency::AUD, Currency::JPY),
+        }
+    }
+
+    /// How the pair's quote currency is valued in USD
+    pub fn quote_conversion(&self) -> QuoteConversion {
+        match self {
+            FxPair::EURUSD | FxPair::GBPUSD | FxPair::AUDUSD | FxPair::NZDUSD => QuoteConversion::Usd,
+            FxPair::USDJPY | FxPair::USDCAD | FxPair::USDCHF => QuoteConversion::InversePair,
+            FxPair::EURGBP => QuoteConversion::Oracle { pair: FxPair::GBPUSD, inverse: false },
+            FxPair::EURJPY | FxPair::GBPJPY | FxPair::AUDJPY => {
+                QuoteConversion::Oracle { pair: FxPair::USDJPY, inverse: true }
+            }
+        }
+    }
+}
+
+/// Source of a pair's quote-currency-to-USD rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteConversion {
+    /// Quote currency is USD
+    Usd,
+
+    /// USD is the base: one quote unit is worth 1 / pair price
+    InversePair,
+
+    /// Cross pair: a second oracle prices the quote currency against USD
+    /// (`inverse` when USD is that pair's base, e.g. USDJPY)
+    Oracle { pair: FxPair, inverse: bool },
+}
+
+/// Currencies the FX pairs are made of (indexes currency_risk_weights_bps)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Currency {
+    USD = 0,
+    EUR = 1,
+    GBP = 2,
+    JPY = 3,
+    AUD = 4,
+    CAD = 5,
+    CHF = 6,
+    NZD = 7,
+}
+
+impl Currency {
+    /// Number of currencies
+    pub const COUNT: usize = 8;
 }
 
 /// Position direction
@@ -122,3 +404,90 @@ impl Direction {
         }
     }
 }
+
+/// Why a position was closed (recorded in PositionClosed)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CloseReason {
+    Manual = 0,
+    Deleverage = 1,
+    Settlement = 2,
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::state::TradingConfigV0;
+
+    /// Baseline config migrated to the current layout
+    pub(in crate::state) fn trading_config() -> TradingConfig {
+        TradingConfigV0 {
+            authority: Pubkey::default(),
+            otus_treasury: Pubkey::default(),
+            usdc_vault: Pubkey::default(),
+            usd1_vault: Pubkey::default(),
+            trading_fee_bps: 10,
+            keeper_fee_bps: 50,
+            max_leverage: 100,
+            min_leverage: 1,
+            deleverage_thresholds: [50, 30, 20, 10],
+            pyth_program: Pubkey::default(),
+            max_price_age: 60,
+            max_price_confidence_bps: 100,
+            is_paused: false,
+            position_counter: 0,
+            bump: 0,
+        }
+        .migrate(0)
+    }
+
+    #[test]
+    fn test_payouts_held_by_close_pause() {
+        let mut config = trading_config();
+        assert!(config.require_payouts_active().is_ok());
+
+        config.paused_actions = PauseFlags::CLOSE;
+        assert!(config.require_payouts_active().is_err());
+    }
+
+    #[test]
+    fn test_settlement_backing_excludes_other_liabilities() {
+        let mut config = trading_config();
+        config.accrued_fees = 100;
+        config.insurance_fund = 200;
+        config.total_pending_outflow = 300;
+        config.total_referral_owed = 50;
+        config.total_margin_owed = 50;
+
+        assert_eq!(config.settlement_backing(1_700).unwrap(), 1_000);
+        // A vault that cannot even cover the other liabilities backs no margin
+        assert_eq!(config.settlement_backing(500).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_queued_outflow_releases_after_settlement() {
+        let mut config = trading_config();
+        config.usdc_outflow_limiter = OutflowLimiter::new(1_000, 3_600, 0);
+        config.outflow_release_delay = 600;
+
+        // Settlement pauses every action for good
+        config.global_settlement = true;
+        config.paused_actions = PauseFlags::ALL;
+
+        assert!(!config.usdc_outflow_limiter.has_capacity(1_500, 0));
+        let (outflow_id, release_at) = config.queue_outflow(1_500, 0).unwrap();
+        assert_eq!(outflow_id, 0);
+        assert_eq!(release_at, 600);
+        assert_eq!(config.total_pending_outflow, 1_500);
+
+        assert!(config.release_outflow(1_500, release_at, 599).is_err());
+
+        // The bucket holds 1_000, so the release is split across windows
+        let released = config.release_outflow(1_500, release_at, 600).unwrap();
+        assert_eq!(released, 1_000);
+        assert_eq!(config.total_pending_outflow, 500);
+
+        let released = config.release_outflow(500, release_at, 600 + 3_600).unwrap();
+        assert_eq!(released, 500);
+        assert_eq!(config.total_pending_outflow, 0);
+    }
+}
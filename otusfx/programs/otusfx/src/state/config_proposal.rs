@@ -0,0 +1,436 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use super::{TradingConfig, TraderAccount, FeeSchedule, VolumeTier, FxPair, PairConfig, LeverageTier, MAX_FEE_BPS};
+
+/// Minimum delay between queueing and executing a config change (1 hour)
+pub const MIN_TIMELOCK_DELAY: i64 = 60 * 60;
+
+/// A set of config changes applied atomically when a proposal executes
+/// Fields left as None keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct ConfigUpdate {
+    pub fee_schedule: Option<FeeSchedule>,
+    pub keeper_fee_bps: Option<u16>,
+    pub max_leverage: Option<u8>,
+    pub min_keeper_reward: Option<[u64; 4]>,
+    pub max_keeper_reward: Option<[u64; 4]>,
+    pub keeper_fee_start_bps: Option<u16>,
+    pub keeper_fee_ramp_secs: Option<i64>,
+    pub min_restore_health: Option<u8>,
+    pub guardian: Option<Pubkey>,
+    pub timelock_delay: Option<i64>,
+    pub reconcile_tolerance: Option<u64>,
+    pub outflow_max: Option<u64>,
+    pub outflow_window_secs: Option<i64>,
+    pub outflow_release_delay: Option<i64>,
+    pub max_open_positions_per_trader: Option<u8>,
+    pub scops_discount_bps: Option<[u16; 5]>,
+    pub volume_tiers: Option<[VolumeTier; 4]>,
+    pub referral_share_bps: Option<u16>,
+    pub referral_rebate_bps: Option<u16>,
+    pub currency_risk_weights_bps: Option<[u16; 8]>,
+    pub portfolio_margin_floor_bps: Option<u16>,
+    pub min_cross_health: Option<u8>,
+    pub pair: Option<PairConfigUpdate>,
+}
+
+impl ConfigUpdate {
+    pub const LEN: usize = 1 + FeeSchedule::LEN + // fee_schedule
+        3 +                       // keeper_fee_bps
+        2 +                       // max_leverage
+        33 +                      // min_keeper_reward
+        33 +                      // max_keeper_reward
+        3 +                       // keeper_fee_start_bps
+        9 +                       // keeper_fee_ramp_secs
+        2 +                       // min_restore_health
+        33 +                      // guardian
+        9 +                       // timelock_delay
+        9 +                       // reconcile_tolerance
+        9 +                       // outflow_max
+        9 +                       // outflow_window_secs
+        9 +                       // outflow_release_delay
+        2 +                       // max_open_positions_per_trader
+        11 +                      // scops_discount_bps
+        1 + 4 * VolumeTier::LEN + // volume_tiers
+        3 +                       // referral_share_bps
+        3 +                       // referral_rebate_bps
+        17 +                      // currency_risk_weights_bps
+        3 +                       // portfolio_margin_floor_bps
+        2 +                       // min_cross_health
+        1 + PairConfigUpdate::LEN; // pair
+
+    /// Apply the update to the config, validating the resulting state
+    pub fn apply(&self, config: &mut TradingConfig) -> Result<()> {
+        if let Some(schedule) = self.fee_schedule {
+            schedule.validate()?;
+            config.fee_schedule = schedule;
+            config.trading_fee_bps = schedule.open_bps;
+            msg!("Updated fee schedule to {:?}", schedule);
+        }
+
+        if let Some(fee) = self.keeper_fee_bps {
+            require!(fee <= MAX_FEE_BPS, TradingError::InvalidKeeperAuction);
+            config.keeper_fee_bps = fee;
+            msg!("Updated keeper fee to {} bps", fee);
+        }
+
+        if let Some(fee) = self.keeper_fee_start_bps {
+            config.keeper_fee_start_bps = fee;
+            msg!("Updated keeper fee start to {} bps", fee);
+        }
+
+        if let Some(ramp) = self.keeper_fee_ramp_secs {
+            require!(ramp >= 0, TradingError::InvalidKeeperAuction);
+            config.keeper_fee_ramp_secs = ramp;
+            msg!("Updated keeper fee ramp to {}s", ramp);
+        }
+
+        require!(
+            config.keeper_fee_start_bps <= config.keeper_fee_bps,
+            TradingError::InvalidKeeperAuction
+        );
+
+        if let Some(leverage) = self.max_leverage {
+            require!(
+                leverage > 0 && leverage >= config.min_leverage,
+                TradingError::InvalidLeverage
+            );
+            config.max_leverage = leverage;
+            msg!("Updated max leverage to {}x", leverage);
+        }
+
+        if let Some(min_rewards) = self.min_keeper_reward {
+            config.min_keeper_reward = min_rewards;
+            msg!("Updated min keeper rewards to {:?} USDC", min_rewards);
+        }
+
+        if let Some(max_rewards) = self.max_keeper_reward {
+            config.max_keeper_reward = max_rewards;
+            msg!("Updated max keeper rewards to {:?} USDC", max_rewards);
+        }
+
+        require!(
+            config.min_keeper_reward
+                .iter()
+                .zip(config.max_keeper_reward.iter())
+                .all(|(min, max)| min <= max),
+            TradingError::InvalidKeeperRewardBounds
+        );
+
+        if let Some(health) = self.min_restore_health {
+            config.min_restore_health = health;
+            msg!("Updated min restore health to {}%", health);
+        }
+
+        if let Some(guardian) = self.guardian {
+            config.guardian = guardian;
+            msg!("Updated guardian to {}", guardian);
+        }
+
+        if let Some(delay) = self.timelock_delay {
+            require!(delay >= MIN_TIMELOCK_DELAY, TradingError::InvalidTimelockDelay);
+            config.timelock_delay = delay;
+            msg!("Updated timelock delay to {}s", delay);
+        }
+
+        if let Some(tolerance) = self.reconcile_tolerance {
+            config.reconcile_tolerance = tolerance;
+            msg!("Updated reconcile tolerance to {} USDC", tolerance);
+        }
+
+        if let Some(max_outflow) = self.outflow_max {
+            let limiter = &mut config.usdc_outflow_limiter;
+            limiter.max_outflow = max_outflow;
+            limiter.available = limiter.available.min(max_outflow);
+            msg!("Updated max outflow to {} USDC per window", max_outflow);
+        }
+
+        if let Some(window) = self.outflow_window_secs {
+            require!(window > 0, TradingError::InvalidOutflowLimit);
+            config.usdc_outflow_limiter.window_secs = window;
+            msg!("Updated outflow window to {}s", window);
+        }
+
+        if let Some(delay) = self.outflow_release_delay {
+            require!(delay >= 0, TradingError::InvalidOutflowLimit);
+            config.outflow_release_delay = delay;
+            msg!("Updated outflow release delay to {}s", delay);
+        }
+
+        if let Some(max_positions) = self.max_open_positions_per_trader {
+            require!(
+                max_positions > 0 && max_positions as usize <= TraderAccount::MAX_OPEN_POSITIONS,
+                TradingError::InvalidMaxOpenPositions
+            );
+            config.max_open_positions_per_trader = max_positions;
+            msg!("Updated max open positions per trader to {}", max_positions);
+        }
+
+        if let Some(discounts) = self.scops_discount_bps {
+            require!(
+                discounts.iter().all(|&bps| bps <= 10_000),
+                TradingError::InvalidScopsDiscount
+            );
+            config.scops_discount_bps = discounts;
+            msg!("Updated Scops fee discounts to {:?} bps", discounts);
+        }
+
+        if let Some(tiers) = self.volume_tiers {
+            VolumeTier::validate_ladder(&tiers)?;
+            config.volume_tiers = tiers;
+            msg!("Updated volume fee tiers to {:?}", tiers);
+        }
+
+        if let Some(share) = self.referral_share_bps {
+            config.referral_share_bps = share;
+            msg!("Updated referral fee share to {} bps", share);
+        }
+
+        if let Some(rebate) = self.referral_rebate_bps {
+            config.referral_rebate_bps = rebate;
+            msg!("Updated referral rebate to {} bps", rebate);
+        }
+
+        require!(
+            config.referral_share_bps as u32 + config.referral_rebate_bps as u32 <= 10_000,
+            TradingError::InvalidReferralShares
+        );
+
+        if let Some(weights) = self.currency_risk_weights_bps {
+            require!(
+                weights.iter().all(|&bps| bps > 0 && bps <= 10_000),
+                TradingError::InvalidCurrencyRiskWeights
+            );
+            config.currency_risk_weights_bps = weights;
+            msg!("Updated currency risk weights to {:?} bps", weights);
+        }
+
+        if let Some(floor) = self.portfolio_margin_floor_bps {
+            require!(floor <= 10_000, TradingError::InvalidPortfolioMarginFloor);
+            config.portfolio_margin_floor_bps = floor;
+            msg!("Updated portfolio margin floor to {} bps", floor);
+        }
+
+        if let Some(health) = self.min_cross_health {
+            config.min_cross_health = health;
+            msg!("Updated min cross-margin health to {}%", health);
+        }
+
+        Ok(())
+    }
+}
+
+/// Changes to a single pair's config, carried by a config proposal
+/// Fields left as None keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PairConfigUpdate {
+    pub pair: FxPair,
+    pub max_leverage: Option<u8>,
+    pub leverage_tiers: Option<[LeverageTier; 4]>,
+    pub reference_volatility_bps: Option<u32>,
+    pub min_leverage_scale_bps: Option<u16>,
+    pub max_threshold_scale_bps: Option<u16>,
+    pub breaker_max_move_bps: Option<u16>,
+    pub breaker_window_secs: Option<u32>,
+    pub breaker_cooldown_secs: Option<u32>,
+    pub skew_scale: Option<u64>,
+    pub max_price_impact_bps: Option<u16>,
+    pub price_feed: Option<Pubkey>,
+    pub quote_price_feed: Option<Pubkey>,
+}
+
+impl PairConfigUpdate {
+    pub const LEN: usize = 1 +   // pair
+        2 +                       // max_leverage
+        1 + 4 * LeverageTier::LEN + // leverage_tiers
+        5 +                       // reference_volatility_bps
+        3 +                       // min_leverage_scale_bps
+        3 +                       // max_threshold_scale_bps
+        3 +                       // breaker_max_move_bps
+        5 +                       // breaker_window_secs
+        5 +                       // breaker_cooldown_secs
+        9 +                       // skew_scale
+        3 +                       // max_price_impact_bps
+        33 +                      // price_feed
+        33;                       // quote_price_feed
+
+    /// Apply the update to the pair's config, validating the resulting state against the global config
+    pub fn apply(&self, config: &TradingConfig, pair_config: &mut PairConfig) -> Result<()> {
+        require!(pair_config.pair == self.pair, TradingError::InvalidPairConfig);
+
+        if let Some(leverage) = self.max_leverage {
+            pair_config.max_leverage = leverage;
+            msg!("Updated {:?} max leverage to {}x", self.pair, leverage);
+        }
+
+        if let Some(tiers) = self.leverage_tiers {
+            LeverageTier::validate_curve(&tiers)?;
+            pair_config.leverage_tiers = tiers;
+            msg!("Updated {:?} leverage tiers to {:?}", self.pair, tiers);
+        }
+
+        // 0 leaves the pair on the global cap
+        require!(
+            pair_config.max_leverage == 0 || pair_config.max_leverage >= config.min_leverage,
+            TradingError::InvalidLeverage
+        );
+        require!(
+            pair_config.leverage_tiers
+                .iter()
+                .filter(|tier| tier.min_notional > 0)
+                .all(|tier| tier.max_leverage >= config.min_leverage),
+            TradingError::InvalidLeverageTiers
+        );
+
+        if let Some(reference) = self.reference_volatility_bps {
+            pair_config.reference_volatility_bps = reference;
+            msg!("Updated {:?} reference volatility to {} bps", self.pair, reference);
+        }
+
+        if let Some(scale) = self.min_leverage_scale_bps {
+            pair_config.min_leverage_scale_bps = scale;
+            msg!("Updated {:?} min leverage scale to {} bps", self.pair, scale);
+        }
+
+        if let Some(scale) = self.max_threshold_scale_bps {
+            pair_config.max_threshold_scale_bps = scale;
+            msg!("Updated {:?} max threshold scale to {} bps", self.pair, scale);
+        }
+
+        // Leverage may shrink but never to zero; thresholds may rise but the first tier must stay below 100%
+        require!(
+            pair_config.min_leverage_scale_bps > 0 && pair_config.min_leverage_scale_bps <= 10_000,
+            TradingError::InvalidVolatilityBounds
+        );
+        require!(
+            pair_config.max_threshold_scale_bps >= 10_000
+                && (config.deleverage_thresholds[0] as u32 * pair_config.max_threshold_scale_bps as u32) < 100 * 10_000,
+            TradingError::InvalidVolatilityBounds
+        );
+
+        if let Some(max_move) = self.breaker_max_move_bps {
+            pair_config.breaker_max_move_bps = max_move;
+            msg!("Updated {:?} circuit breaker move to {} bps", self.pair, max_move);
+        }
+
+        if let Some(window) = self.breaker_window_secs {
+            pair_config.breaker_window_secs = window;
+            msg!("Updated {:?} circuit breaker window to {}s", self.pair, window);
+        }
+
+        if let Some(cooldown) = self.breaker_cooldown_secs {
+            pair_config.breaker_cooldown_secs = cooldown;
+            msg!("Updated {:?} circuit breaker cool-down to {}s", self.pair, cooldown);
+        }
+
+        // An enabled breaker needs a window to measure moves over
+        require!(
+            pair_config.breaker_max_move_bps == 0
+                || (pair_config.breaker_max_move_bps <= 10_000 && pair_config.breaker_window_secs > 0),
+            TradingError::InvalidCircuitBreaker
+        );
+
+        if let Some(scale) = self.skew_scale {
+            pair_config.skew_scale = scale;
+            msg!("Updated {:?} skew scale to {} USDC", self.pair, scale);
+        }
+
+        if let Some(impact) = self.max_price_impact_bps {
+            require!(impact <= PairConfig::MAX_PRICE_IMPACT_BPS, TradingError::InvalidPriceImpact);
+            pair_config.max_price_impact_bps = impact;
+            msg!("Updated {:?} max price impact to {} bps", self.pair, impact);
+        }
+
+        if let Some(feed) = self.price_feed {
+            pair_config.price_feed = feed;
+            msg!("Updated {:?} price feed to {}", self.pair, feed);
+        }
+
+        if let Some(feed) = self.quote_price_feed {
+            pair_config.quote_price_feed = feed;
+            msg!("Updated {:?} quote price feed to {}", self.pair, feed);
+        }
+
+        Ok(())
+    }
+}
+
+/// A queued config change awaiting its timelock
+#[account]
+pub struct ConfigProposal {
+    /// Sequential proposal ID (from config.proposal_counter)
+    pub proposal_id: u64,
+
+    /// Changes to apply on execution
+    pub update: ConfigUpdate,
+
+    /// Account that paid rent for the proposal (refunded on execute/cancel)
+    pub rent_payer: Pubkey,
+
+    /// Timestamp when the proposal was queued
+    pub queued_at: i64,
+
+    /// Earliest timestamp the proposal can be executed
+    pub eta: i64,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl ConfigProposal {
+    pub const LEN: usize = 8 +   // discriminator
+        8 +                       // proposal_id
+        ConfigUpdate::LEN +       // update
+        32 +                      // rent_payer
+        8 +                       // queued_at
+        8 +                       // eta
+        1 +                       // bump
+        1 +                       // version
+        16;                       // reserved
+
+    pub const SEED_PREFIX: &'static [u8] = b"config_proposal";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::config::tests::trading_config;
+
+    #[test]
+    fn test_rejects_invalid_max_leverage() {
+        let mut config = trading_config();
+        config.min_leverage = 2;
+
+        for leverage in [0, 1] {
+            let update = ConfigUpdate { max_leverage: Some(leverage), ..Default::default() };
+            assert!(update.apply(&mut config).is_err());
+        }
+        assert_eq!(config.max_leverage, 100);
+
+        let update = ConfigUpdate { max_leverage: Some(2), ..Default::default() };
+        assert!(update.apply(&mut config).is_ok());
+        assert_eq!(config.max_leverage, 2);
+    }
+
+    #[test]
+    fn test_rejects_keeper_fee_above_cap() {
+        let mut config = trading_config();
+
+        let update = ConfigUpdate { keeper_fee_bps: Some(MAX_FEE_BPS + 1), ..Default::default() };
+        assert!(update.apply(&mut config).is_err());
+        assert_eq!(config.keeper_fee_bps, 50);
+
+        let update = ConfigUpdate { keeper_fee_bps: Some(MAX_FEE_BPS), ..Default::default() };
+        assert!(update.apply(&mut config).is_ok());
+        assert_eq!(config.keeper_fee_bps, MAX_FEE_BPS);
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+
+/// Opt-in cross-margin account: collateral deposited once and shared by the owner's cross positions
+/// Health is measured across every position, so hedged positions offset each other
+#[account]
+pub struct CrossMarginAccount {
+    /// Trader this account belongs to
+    pub owner: Pubkey,
+
+    /// USDC collateral held for the account (6 decimals), including margin allocated to positions
+    pub collateral: u64,
+
+    /// Sum of the initial margin allocated to open cross positions
+    pub allocated_margin: u64,
+
+    /// IDs of the account's open cross positions (bounded by MAX_POSITIONS)
+    pub position_ids: Vec<u64>,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Health is measured against net per-currency exposure instead of allocated margin
+    pub portfolio_margin: bool,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 31],
+}
+
+impl CrossMarginAccount {
+    /// Hard cap on cross positions per account (bounds the accounts passed for health checks)
+    pub const MAX_POSITIONS: usize = 8;
+
+    pub const LEN: usize = 8 +   // discriminator
+        32 +                      // owner
+        8 +                       // collateral
+        8 +                       // allocated_margin
+        4 + 8 * Self::MAX_POSITIONS + // position_ids
+        1 +                       // bump
+        1 +                       // version
+        1 +                       // portfolio_margin
+        31;                       // reserved
+
+    pub const SEED_PREFIX: &'static [u8] = b"cross_margin";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Collateral not allocated to any position
+    pub fn free_collateral(&self) -> u64 {
+        self.collateral.saturating_sub(self.allocated_margin)
+    }
+
+    /// Allocate margin to a new position
+    /// Outside portfolio margin the allocation must fit in the free collateral
+    pub fn add_position(&mut self, position_id: u64, margin: u64) -> Result<()> {
+        require!(
+            self.position_ids.len() < Self::MAX_POSITIONS,
+            TradingError::TooManyOpenPositions
+        );
+        require!(
+            self.portfolio_margin || margin <= self.free_collateral(),
+            TradingError::InsufficientCrossCollateral
+        );
+
+        self.position_ids.push(position_id);
+        self.allocated_margin = self.allocated_margin
+            .checked_add(margin)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release a closed position's allocation
+    pub fn remove_position(&mut self, position_id: u64, margin: u64) {
+        if let Some(index) = self.position_ids.iter().position(|&id| id == position_id) {
+            self.position_ids.swap_remove(index);
+        }
+        self.allocated_margin = self.allocated_margin.saturating_sub(margin);
+    }
+
+    /// Apply realized PnL to the collateral; losses beyond the collateral are absorbed (bad debt)
+    pub fn realize_pnl(&mut self, pnl: i64) -> Result<()> {
+        self.collateral = if pnl >= 0 {
+            self.collateral
+                .checked_add(pnl as u64)
+                .ok_or(TradingError::ArithmeticOverflow)?
+        } else {
+            self.collateral.saturating_sub(pnl.unsigned_abs())
+        };
+        Ok(())
+    }
+}
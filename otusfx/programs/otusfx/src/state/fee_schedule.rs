@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::utils::calculate_trading_fee;
+
+/// Upper bound for any single fee rate (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Fee rates charged on every size-changing action, in basis points of notional
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct FeeSchedule {
+    /// Fee on notional opened (open_position, restore_leverage)
+    pub open_bps: u16,
+
+    /// Fee on notional closed by the owner (close_position)
+    pub close_bps: u16,
+
+    /// Fee on notional closed by an auto-deleverage tier (trigger_deleverage)
+    pub deleverage_bps: u16,
+
+    /// Extra penalty on notional closed by the final deleverage tier (liquidation)
+    pub liquidation_penalty_bps: u16,
+}
+
+impl FeeSchedule {
+    pub const LEN: usize = 2 +   // open_bps
+        2 +                       // close_bps
+        2 +                       // deleverage_bps
+        2;                        // liquidation_penalty_bps
+
+    /// Flat schedule charging the same rate on every action, with no liquidation penalty
+    pub fn flat(fee_bps: u16) -> Self {
+        Self {
+            open_bps: fee_bps,
+            close_bps: fee_bps,
+            deleverage_bps: fee_bps,
+            liquidation_penalty_bps: 0,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.open_bps <= MAX_FEE_BPS
+                && self.close_bps <= MAX_FEE_BPS
+                && self.deleverage_bps <= MAX_FEE_BPS
+                && self.liquidation_penalty_bps <= MAX_FEE_BPS,
+            TradingError::InvalidFeeSchedule
+        );
+        Ok(())
+    }
+
+    pub fn open_fee(&self, notional: u64) -> Result<u64> {
+        calculate_trading_fee(notional, self.open_bps)
+    }
+
+    pub fn close_fee(&self, notional: u64) -> Result<u64> {
+        calculate_trading_fee(notional, self.close_bps)
+    }
+
+    pub fn deleverage_fee(&self, notional: u64) -> Result<u64> {
+        calculate_trading_fee(notional, self.deleverage_bps)
+    }
+
+    pub fn liquidation_penalty(&self, notional: u64) -> Result<u64> {
+        calculate_trading_fee(notional, self.liquidation_penalty_bps)
+    }
+}
+
+/// Step of the volume fee ladder: traders at or above `min_volume` get `discount_bps`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct VolumeTier {
+    /// Rolling 30-day notional volume required (USDC, 6 decimals; 0 disables the tier)
+    pub min_volume: u64,
+
+    /// Discount on open and close fees in basis points
+    pub discount_bps: u16,
+}
+
+impl VolumeTier {
+    pub const LEN: usize = 8 +   // min_volume
+        2;                        // discount_bps
+
+    /// Number of steps in the ladder
+    pub const COUNT: usize = 4;
+
+    /// Enabled steps must require strictly rising volume; discounts are at most 100%
+    pub fn validate_ladder(tiers: &[VolumeTier; Self::COUNT]) -> Result<()> {
+        require!(
+            tiers.iter().all(|tier| tier.discount_bps <= 10_000),
+            TradingError::InvalidVolumeTiers
+        );
+
+        let mut previous = 0;
+        for tier in tiers.iter().filter(|tier| tier.min_volume > 0) {
+            require!(tier.min_volume > previous, TradingError::InvalidVolumeTiers);
+            previous = tier.min_volume;
+        }
+        Ok(())
+    }
+
+    /// Discount for a rolling volume: the highest enabled step reached
+    pub fn discount_for(tiers: &[VolumeTier; Self::COUNT], volume: u64) -> u16 {
+        tiers
+            .iter()
+            .filter(|tier| tier.min_volume > 0 && volume >= tier.min_volume)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+}
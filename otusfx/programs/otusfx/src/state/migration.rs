@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use super::{
+    TradingConfig, Position, FxPair, Direction, StablecoinType, PauseFlags,
+    OutflowLimiter, FeeSchedule, VolumeTier,
+};
+
+/// TradingConfig as first deployed (before the version byte existed)
+///
+/// The current layout inserts fields mid-struct, so these accounts cannot be read with
+/// it; they are recognised by their length.
+#[derive(AnchorDeserialize)]
+pub struct TradingConfigV0 {
+    pub authority: Pubkey,
+    pub otus_treasury: Pubkey,
+    pub usdc_vault: Pubkey,
+    pub usd1_vault: Pubkey,
+    pub trading_fee_bps: u16,
+    pub keeper_fee_bps: u16,
+    pub max_leverage: u8,
+    pub min_leverage: u8,
+    pub deleverage_thresholds: [u8; 4],
+    pub pyth_program: Pubkey,
+    pub max_price_age: i64,
+    pub max_price_confidence_bps: u16,
+    pub is_paused: bool,
+    pub position_counter: u64,
+    pub bump: u8,
+}
+
+impl TradingConfigV0 {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +                     // authority
+        32 +                     // otus_treasury
+        32 +                     // usdc_vault
+        32 +                     // usd1_vault
+        2 +                      // trading_fee_bps
+        2 +                      // keeper_fee_bps
+        1 +                      // max_leverage
+        1 +                      // min_leverage
+        4 +                      // deleverage_thresholds
+        32 +                     // pyth_program
+        8 +                      // max_price_age
+        2 +                      // max_price_confidence_bps
+        1 +                      // is_paused
+        8 +                      // position_counter
+        1;                       // bump
+
+    /// Convert to the current layout at version 0, filling the new fields with initialize's
+    /// defaults; the fee schedule keeps the open fee and starts every other fee at zero
+    ///
+    /// Open position and margin totals start at zero; migrate_position adds each
+    /// open legacy position back as it is migrated.
+    pub fn migrate(self, now: i64) -> TradingConfig {
+        TradingConfig {
+            authority: self.authority,
+            pending_authority: Pubkey::default(),
+            guardian: self.authority,
+            timelock_delay: 48 * 60 * 60, // 48 hours
+            proposal_counter: 0,
+            otus_treasury: self.otus_treasury,
+            usdc_vault: self.usdc_vault,
+            usd1_vault: self.usd1_vault,
+            trading_fee_bps: self.trading_fee_bps,
+            keeper_fee_bps: self.keeper_fee_bps,
+            keeper_fee_start_bps: self.keeper_fee_bps / 5,
+            keeper_fee_ramp_secs: 300, // 5 minutes
+            max_leverage: self.max_leverage,
+            min_leverage: self.min_leverage,
+            deleverage_thresholds: self.deleverage_thresholds,
+            min_restore_health: 80,
+            pyth_program: self.pyth_program,
+            max_price_age: self.max_price_age,
+            max_price_confidence_bps: self.max_price_confidence_bps,
+            paused_actions: if self.is_paused { PauseFlags::ALL } else { 0 },
+            position_counter: self.position_counter,
+            insurance_fund: 0,
+            accrued_fees: 0,
+            reconcile_tolerance: 1_000_000, // $1
+            usdc_outflow_limiter: OutflowLimiter::new(
+                100_000_000_000, // $100k
+                60 * 60,         // per hour
+                now,
+            ),
+            outflow_release_delay: 6 * 60 * 60, // 6 hours
+            outflow_counter: 0,
+            total_pending_outflow: 0,
+            min_keeper_reward: [500_000; 4], // $0.50
+            max_keeper_reward: [250_000_000; 4], // $250
+            open_position_count: 0,
+            total_open_margin: 0,
+            global_settlement: false,
+            settled_at: 0,
+            settlement_payout_bps: 10_000,
+            bump: self.bump,
+            version: 0,
+            max_open_positions_per_trader: TradingConfig::DEFAULT_MAX_OPEN_POSITIONS,
+            fee_schedule: FeeSchedule {
+                open_bps: self.trading_fee_bps,
+                ..FeeSchedule::default()
+            },
+            scops_discount_bps: [0; 5],
+            volume_tiers: [VolumeTier::default(); 4],
+            referral_share_bps: 0,
+            referral_rebate_bps: 0,
+            total_referral_owed: 0,
+            total_cross_collateral: 0,
+            currency_risk_weights_bps: [0; 8],
+            total_margin_owed: 0,
+            portfolio_margin_floor_bps: 100, // 1%
+            min_cross_health: 80,
+            reserved: [0; 22],
+        }
+    }
+}
+
+/// Position as first deployed (before trigger_crossed_at and the version byte)
+#[derive(AnchorDeserialize)]
+pub struct PositionV0 {
+    pub owner: Pubkey,
+    pub position_id: u64,
+    pub is_open: bool,
+    pub pair: FxPair,
+    pub direction: Direction,
+    pub collateral_type: StablecoinType,
+    pub leverage: u8,
+    pub initial_leverage: u8,
+    pub margin: u64,
+    pub initial_margin: u64,
+    pub size: u64,
+    pub entry_price: i64,
+    pub entry_price_expo: i32,
+    pub trigger_prices: [i64; 4],
+    pub deleverage_executed: [bool; 4],
+    pub opened_at: i64,
+    pub closed_at: Option<i64>,
+    pub final_pnl_otus: Option<i64>,
+    pub bump: u8,
+}
+
+impl PositionV0 {
+    pub const LEN: usize = 8 +   // discriminator
+        32 +                      // owner
+        8 +                       // position_id
+        1 +                       // is_open
+        1 +                       // pair
+        1 +                       // direction
+        1 +                       // collateral_type
+        1 +                       // leverage
+        1 +                       // initial_leverage
+        8 +                       // margin
+        8 +                       // initial_margin
+        8 +                       // size
+        8 +                       // entry_price
+        4 +                       // entry_price_expo
+        32 +                      // trigger_prices (4 * 8)
+        4 +                       // deleverage_executed (4 * 1)
+        8 +                       // opened_at
+        9 +                       // closed_at (Option<i64>)
+        9 +                       // final_pnl_otus (Option<i64>)
+        1;                        // bump
+
+    /// Convert to the current layout at version 0 (no crossings recorded, isolated margin)
+    pub fn migrate(self) -> Position {
+        Position {
+            owner: self.owner,
+            position_id: self.position_id,
+            is_open: self.is_open,
+            pair: self.pair,
+            direction: self.direction,
+            collateral_type: self.collateral_type,
+            leverage: self.leverage,
+            initial_leverage: self.initial_leverage,
+            margin: self.margin,
+            initial_margin: self.initial_margin,
+            size: self.size,
+            entry_price: self.entry_price,
+            entry_price_expo: self.entry_price_expo,
+            trigger_prices: self.trigger_prices,
+            deleverage_executed: self.deleverage_executed,
+            trigger_crossed_at: [0; 4],
+            opened_at: self.opened_at,
+            closed_at: self.closed_at,
+            final_pnl_otus: self.final_pnl_otus,
+            bump: self.bump,
+            version: 0,
+            cross_margin: false,
+            entry_quote_price: 0,
+            entry_quote_price_expo: 0,
+            realized_pnl: 0,
+            reserved: [0; 43],
+        }
+    }
+}
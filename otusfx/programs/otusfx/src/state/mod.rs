@@ -1,8 +1,24 @@
 pub mod config;
 pub mod position;
+pub mod config_proposal;
+pub mod pair_config;
+pub mod rate_limiter;
+pub mod migration;
+pub mod trader_account;
+pub mod fee_schedule;
+pub mod referral;
+pub mod cross_margin;
 
 pub use config::*;
 pub use position::*;
+pub use config_proposal::*;
+pub use pair_config::*;
+pub use rate_limiter::*;
+pub use migration::*;
+pub use trader_account::*;
+pub use fee_schedule::*;
+pub use referral::*;
+pub use cross_margin::*;
 
 use anchor_lang::prelude::*;
 
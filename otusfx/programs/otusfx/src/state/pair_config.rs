@@ -0,0 +1,566 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::utils::{
+    calculate_volatility_sample_bps, calculate_volatility_ratio_bps, calculate_execution_price, calculate_price_distance_bps,
+};
+use super::{FxPair, Direction};
+
+/// Pause bitmask flags shared by `TradingConfig.paused_actions` and `PairConfig.paused_actions`
+pub struct PauseFlags;
+
+impl PauseFlags {
+    /// Opening positions and restoring leverage
+    pub const OPEN: u8 = 1 << 0;
+
+    /// Closing positions
+    pub const CLOSE: u8 = 1 << 1;
+
+    /// Keeper deleverage (including recording trigger crossings)
+    pub const DELEVERAGE: u8 = 1 << 2;
+
+    /// Keeper execution of resting orders
+    pub const EXECUTE_ORDERS: u8 = 1 << 3;
+
+    /// Every pausable action
+    pub const ALL: u8 = Self::OPEN | Self::CLOSE | Self::DELEVERAGE | Self::EXECUTE_ORDERS;
+
+    /// Fail with the action's pause error if it is paused globally or for the pair
+    pub fn require_active(global: u8, pair: u8, action: u8) -> Result<()> {
+        if (global | pair) & action == 0 {
+            return Ok(());
+        }
+
+        match action {
+            Self::OPEN => err!(TradingError::OpenPaused),
+            Self::CLOSE => err!(TradingError::ClosePaused),
+            Self::DELEVERAGE => err!(TradingError::DeleveragePaused),
+            _ => err!(TradingError::OrderExecutionPaused),
+        }
+    }
+}
+
+/// Per-pair trading configuration
+#[account]
+pub struct PairConfig {
+    /// FX pair this config applies to
+    pub pair: FxPair,
+
+    /// Actions paused for this pair only (PauseFlags bitmask)
+    pub paused_actions: u8,
+
+    /// Frozen settlement price used once global settlement is active (0 if not set)
+    pub settlement_price: i64,
+
+    /// Settlement price exponent
+    pub settlement_price_expo: i32,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Maximum leverage for this pair (0 = global max_leverage only)
+    pub max_leverage: u8,
+
+    /// Leverage curve by position notional (all disabled until set)
+    pub leverage_tiers: [LeverageTier; 4],
+
+    /// Rolling volatility estimate (bps of price per sample, EMA of price moves plus confidence)
+    pub volatility_bps: u32,
+
+    /// Oracle price of the last volatility sample
+    pub last_volatility_price: i64,
+
+    /// Publish time of the last volatility sample
+    pub last_volatility_update: i64,
+
+    /// Volatility at which base leverage and thresholds apply (0 disables adaptation)
+    pub reference_volatility_bps: u32,
+
+    /// Floor on the leverage scale as volatility rises (bps)
+    pub min_leverage_scale_bps: u16,
+
+    /// Ceiling on the deleverage threshold scale as volatility rises (bps, >= 10_000)
+    pub max_threshold_scale_bps: u16,
+
+    /// Open long notional (USDC, 6 decimals)
+    pub long_open_interest: u64,
+
+    /// Open short notional (USDC, 6 decimals)
+    pub short_open_interest: u64,
+
+    /// Skew at which the execution premium reaches 100% (USDC notional; 0 disables price impact)
+    pub skew_scale: u64,
+
+    /// Cap on the execution premium or rebate (bps of the oracle price)
+    pub max_price_impact_bps: u16,
+
+    /// Last oracle price accepted by the circuit breaker (0 before the first)
+    pub last_accepted_price: i64,
+
+    /// Timestamp the last price was accepted
+    pub last_accepted_at: i64,
+
+    /// Largest move from the last accepted price allowed within the window (bps; 0 disables the breaker)
+    pub breaker_max_move_bps: u16,
+
+    /// Window over which the move is measured (seconds)
+    pub breaker_window_secs: u32,
+
+    /// Cool-down after a trip during which opens and deleverage pause (seconds)
+    pub breaker_cooldown_secs: u32,
+
+    /// End of the current cool-down (0 if the breaker has not tripped)
+    pub breaker_tripped_until: i64,
+
+    /// Pyth price feed account the pair's oracle prices must come from
+    pub price_feed: Pubkey,
+
+    /// Pyth price feed account of the quote conversion pair (cross pairs only)
+    pub quote_price_feed: Pubkey,
+
+    /// Set once a position has settled at the settlement price; the price can no longer change
+    pub settlement_price_frozen: bool,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 31],
+}
+
+impl PairConfig {
+    pub const LEN: usize = 8 +   // discriminator
+        1 +                       // pair
+        1 +                       // paused_actions
+        8 +                       // settlement_price
+        4 +                       // settlement_price_expo
+        1 +                       // bump
+        1 +                       // version
+        1 +                       // max_leverage
+        4 * LeverageTier::LEN +   // leverage_tiers
+        4 +                       // volatility_bps
+        8 +                       // last_volatility_price
+        8 +                       // last_volatility_update
+        4 +                       // reference_volatility_bps
+        2 +                       // min_leverage_scale_bps
+        2 +                       // max_threshold_scale_bps
+        8 +                       // long_open_interest
+        8 +                       // short_open_interest
+        8 +                       // skew_scale
+        2 +                       // max_price_impact_bps
+        8 +                       // last_accepted_price
+        8 +                       // last_accepted_at
+        2 +                       // breaker_max_move_bps
+        4 +                       // breaker_window_secs
+        4 +                       // breaker_cooldown_secs
+        8 +                       // breaker_tripped_until
+        32 +                      // price_feed
+        32 +                      // quote_price_feed
+        1 +                       // settlement_price_frozen
+        31;                       // reserved
+
+    pub const SEED_PREFIX: &'static [u8] = b"pair";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Minimum time between volatility samples (seconds)
+    pub const VOLATILITY_SAMPLE_INTERVAL: i64 = 60;
+
+    /// EMA weight of the existing estimate (the new sample counts 1 / VOLATILITY_EMA_PERIOD)
+    pub const VOLATILITY_EMA_PERIOD: u64 = 8;
+
+    /// Hard cap on max_price_impact_bps (bps of the oracle price)
+    pub const MAX_PRICE_IMPACT_BPS: u16 = 100;
+
+    /// Largest price move a single volatility sample can contribute (bps)
+    pub const MAX_VOLATILITY_SAMPLE_MOVE_BPS: u64 = 200;
+
+    /// Fold a new oracle price and confidence into the volatility estimate
+    /// Returns false when the previous sample is too recent
+    pub fn record_volatility_sample(&mut self, price: i64, conf: u64, publish_time: i64) -> Result<bool> {
+        if self.last_volatility_update > 0
+            && publish_time < self.last_volatility_update + Self::VOLATILITY_SAMPLE_INTERVAL
+        {
+            return Ok(false);
+        }
+
+        let sample_bps = calculate_volatility_sample_bps(
+            self.last_volatility_price,
+            price,
+            conf,
+            Self::MAX_VOLATILITY_SAMPLE_MOVE_BPS,
+        )?;
+        self.volatility_bps = if self.last_volatility_update == 0 {
+            sample_bps
+        } else {
+            ((self.volatility_bps as u64 * (Self::VOLATILITY_EMA_PERIOD - 1) + sample_bps as u64)
+                / Self::VOLATILITY_EMA_PERIOD) as u32
+        };
+        self.last_volatility_price = price;
+        self.last_volatility_update = publish_time;
+        Ok(true)
+    }
+
+    /// Leverage scale for current volatility (bps): inverse to volatility above the reference, floored
+    pub fn leverage_scale_bps(&self) -> u16 {
+        let ratio_bps = calculate_volatility_ratio_bps(self.volatility_bps, self.reference_volatility_bps);
+        if ratio_bps <= 10_000 {
+            return 10_000;
+        }
+        ((100_000_000 / ratio_bps as u64) as u16).max(self.min_leverage_scale_bps)
+    }
+
+    /// Deleverage thresholds raised in proportion to volatility above the reference, capped
+    pub fn deleverage_thresholds(&self, base_thresholds: &[u8; 4]) -> [u8; 4] {
+        let ratio_bps = calculate_volatility_ratio_bps(self.volatility_bps, self.reference_volatility_bps);
+        let scale_bps = ratio_bps.clamp(10_000, self.max_threshold_scale_bps.max(10_000) as u32);
+        base_thresholds.map(|threshold| (threshold as u32 * scale_bps / 10_000).min(99) as u8)
+    }
+
+    /// Whether the pair is cooling down after a circuit breaker trip
+    pub fn in_breaker_cooldown(&self, now: i64) -> bool {
+        now < self.breaker_tripped_until
+    }
+
+    /// Whether `price` moved further from the last accepted price than the breaker allows
+    /// Only moves within the window count; an older reference is replaced by any price
+    pub fn breaker_move_exceeded(&self, price: i64, now: i64) -> bool {
+        self.breaker_max_move_bps > 0
+            && self.last_accepted_price > 0
+            && now - self.last_accepted_at <= self.breaker_window_secs as i64
+            && calculate_price_distance_bps(self.last_accepted_price, price) > self.breaker_max_move_bps as u64
+    }
+
+    /// Fail if the pair is cooling down or `price` would trip the breaker
+    pub fn require_price_within_breaker(&self, price: i64, now: i64) -> Result<()> {
+        require!(!self.in_breaker_cooldown(now), TradingError::CircuitBreakerCooldown);
+        require!(!self.breaker_move_exceeded(price, now), TradingError::PriceMoveExceedsBreaker);
+        Ok(())
+    }
+
+    /// Check `price` against the breaker and record it as the last accepted price
+    pub fn accept_price(&mut self, price: i64, now: i64) -> Result<()> {
+        self.require_price_within_breaker(price, now)?;
+        self.last_accepted_price = price;
+        self.last_accepted_at = now;
+        Ok(())
+    }
+
+    /// Accept `price`, or trip the breaker and start the cool-down if it moved too far
+    /// Returns false when the breaker tripped
+    pub fn screen_price(&mut self, price: i64, now: i64) -> Result<bool> {
+        require!(!self.in_breaker_cooldown(now), TradingError::CircuitBreakerCooldown);
+
+        if self.breaker_move_exceeded(price, now) {
+            self.breaker_tripped_until = now
+                .checked_add(self.breaker_cooldown_secs as i64)
+                .ok_or(TradingError::ArithmeticOverflow)?;
+            return Ok(false);
+        }
+
+        self.last_accepted_price = price;
+        self.last_accepted_at = now;
+        Ok(true)
+    }
+
+    /// Set the pair's settlement price
+    /// Rejected once a position has settled, so every position in the pair settles at one price
+    pub fn set_settlement_price(&mut self, price: i64, price_expo: i32) -> Result<()> {
+        require!(price > 0, TradingError::InvalidSettlementPrice);
+        require!(!self.settlement_price_frozen, TradingError::SettlementPriceFrozen);
+
+        self.settlement_price = price;
+        self.settlement_price_expo = price_expo;
+        Ok(())
+    }
+
+    /// Net open interest (long minus short notional)
+    pub fn skew(&self) -> i128 {
+        self.long_open_interest as i128 - self.short_open_interest as i128
+    }
+
+    /// Signed notional a trade adds to the skew (positive buys the base currency)
+    pub fn trade_notional(direction: Direction, size: u64, increasing: bool) -> i128 {
+        match (direction, increasing) {
+            (Direction::Long, true) | (Direction::Short, false) => size as i128,
+            (Direction::Long, false) | (Direction::Short, true) => -(size as i128),
+        }
+    }
+
+    /// Price a trade executes at: worse than the oracle when it grows the skew, better when it shrinks it
+    pub fn execution_price(&self, oracle_price: i64, trade_notional: i128) -> Result<i64> {
+        calculate_execution_price(
+            oracle_price,
+            self.skew(),
+            trade_notional,
+            self.skew_scale,
+            self.max_price_impact_bps,
+        )
+    }
+
+    /// Record notional opened on a side
+    pub fn add_open_interest(&mut self, direction: Direction, size: u64) -> Result<()> {
+        let open_interest = match direction {
+            Direction::Long => &mut self.long_open_interest,
+            Direction::Short => &mut self.short_open_interest,
+        };
+        *open_interest = open_interest
+            .checked_add(size)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record notional closed on a side
+    /// Saturates for positions opened before open interest was tracked
+    pub fn remove_open_interest(&mut self, direction: Direction, size: u64) {
+        let open_interest = match direction {
+            Direction::Long => &mut self.long_open_interest,
+            Direction::Short => &mut self.short_open_interest,
+        };
+        *open_interest = open_interest.saturating_sub(size);
+    }
+
+    /// Highest leverage allowed for a position of `notional` on this pair
+    /// The lowest of the global cap, the pair cap and the leverage curve applies, scaled down by volatility
+    pub fn max_leverage_for(&self, notional: u64, global_max_leverage: u8) -> u8 {
+        let mut max_leverage = global_max_leverage;
+        if self.max_leverage > 0 {
+            max_leverage = max_leverage.min(self.max_leverage);
+        }
+        if let Some(curve_max) = LeverageTier::cap_for(&self.leverage_tiers, notional) {
+            max_leverage = max_leverage.min(curve_max);
+        }
+        // Volatility scaling never drops the cap below 1x
+        ((max_leverage as u32 * self.leverage_scale_bps() as u32 / 10_000) as u8).max(1)
+    }
+
+    /// Fail unless `leverage` is within the pair's limit for a position of `notional`
+    pub fn require_leverage(&self, leverage: u8, notional: u64, global_max_leverage: u8) -> Result<()> {
+        require!(
+            leverage <= self.max_leverage_for(notional, global_max_leverage),
+            TradingError::LeverageAboveLimit
+        );
+        Ok(())
+    }
+}
+
+/// Step of a pair's leverage curve: positions of at least `min_notional` are capped at `max_leverage`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct LeverageTier {
+    /// Position notional the cap starts at (USDC, 6 decimals; 0 disables the tier)
+    pub min_notional: u64,
+
+    /// Maximum leverage at or above min_notional
+    pub max_leverage: u8,
+}
+
+impl LeverageTier {
+    pub const LEN: usize = 8 +   // min_notional
+        1;                        // max_leverage
+
+    /// Number of steps in the curve
+    pub const COUNT: usize = 4;
+
+    /// Enabled steps must require strictly rising notional with non-increasing, non-zero caps
+    pub fn validate_curve(tiers: &[LeverageTier; Self::COUNT]) -> Result<()> {
+        let mut previous: Option<&LeverageTier> = None;
+        for tier in tiers.iter().filter(|tier| tier.min_notional > 0) {
+            require!(tier.max_leverage > 0, TradingError::InvalidLeverageTiers);
+            if let Some(previous) = previous {
+                require!(
+                    tier.min_notional > previous.min_notional
+                        && tier.max_leverage <= previous.max_leverage,
+                    TradingError::InvalidLeverageTiers
+                );
+            }
+            previous = Some(tier);
+        }
+        Ok(())
+    }
+
+    /// Cap for a notional: the lowest enabled step reached (None below every step)
+    pub fn cap_for(tiers: &[LeverageTier; Self::COUNT], notional: u64) -> Option<u8> {
+        tiers
+            .iter()
+            .filter(|tier| tier.min_notional > 0 && notional >= tier.min_notional)
+            .map(|tier| tier.max_leverage)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_notional: u64, max_leverage: u8) -> LeverageTier {
+        LeverageTier { min_notional, max_leverage }
+    }
+
+    fn pair_config() -> PairConfig {
+        PairConfig {
+            pair: FxPair::EURUSD,
+            paused_actions: 0,
+            settlement_price: 0,
+            settlement_price_expo: 0,
+            bump: 0,
+            version: PairConfig::VERSION,
+            max_leverage: 0,
+            leverage_tiers: [LeverageTier::default(); 4],
+            volatility_bps: 0,
+            last_volatility_price: 0,
+            last_volatility_update: 0,
+            reference_volatility_bps: 0,
+            min_leverage_scale_bps: 10_000,
+            max_threshold_scale_bps: 10_000,
+            long_open_interest: 0,
+            short_open_interest: 0,
+            skew_scale: 0,
+            max_price_impact_bps: 0,
+            last_accepted_price: 0,
+            last_accepted_at: 0,
+            breaker_max_move_bps: 0,
+            breaker_window_secs: 0,
+            breaker_cooldown_secs: 0,
+            breaker_tripped_until: 0,
+            price_feed: Pubkey::default(),
+            quote_price_feed: Pubkey::default(),
+            settlement_price_frozen: false,
+            reserved: [0; 31],
+        }
+    }
+
+    #[test]
+    fn test_validate_leverage_curve() {
+        // $100k and up capped at 20x, $1M and up at 10x; disabled steps may sit anywhere
+        let curve = [tier(100_000_000_000, 20), tier(0, 0), tier(1_000_000_000_000, 10), tier(0, 0)];
+        assert!(LeverageTier::validate_curve(&curve).is_ok());
+        assert!(LeverageTier::validate_curve(&[LeverageTier::default(); 4]).is_ok());
+
+        // Caps may not rise with notional, notional must strictly rise and enabled caps can't be zero
+        let rising = [tier(100_000_000_000, 10), tier(1_000_000_000_000, 20), tier(0, 0), tier(0, 0)];
+        assert!(LeverageTier::validate_curve(&rising).is_err());
+        let repeated = [tier(100_000_000_000, 20), tier(100_000_000_000, 10), tier(0, 0), tier(0, 0)];
+        assert!(LeverageTier::validate_curve(&repeated).is_err());
+        let zero_cap = [tier(100_000_000_000, 0), tier(0, 0), tier(0, 0), tier(0, 0)];
+        assert!(LeverageTier::validate_curve(&zero_cap).is_err());
+    }
+
+    #[test]
+    fn test_leverage_cap_for() {
+        let curve = [tier(100_000_000_000, 20), tier(1_000_000_000_000, 10), tier(0, 0), tier(0, 0)];
+        assert_eq!(LeverageTier::cap_for(&curve, 99_999_999_999), None);
+        assert_eq!(LeverageTier::cap_for(&curve, 100_000_000_000), Some(20));
+        assert_eq!(LeverageTier::cap_for(&curve, 5_000_000_000_000), Some(10));
+        assert_eq!(LeverageTier::cap_for(&[LeverageTier::default(); 4], u64::MAX), None);
+    }
+
+    #[test]
+    fn test_max_leverage_for() {
+        let mut pair_config = pair_config();
+
+        // Global cap alone, then the lower pair cap, then the curve at size
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 50), 50);
+        pair_config.max_leverage = 30;
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 50), 30);
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 25), 25);
+        pair_config.leverage_tiers = [tier(100_000_000_000, 20), tier(1_000_000_000_000, 10), tier(0, 0), tier(0, 0)];
+        assert_eq!(pair_config.max_leverage_for(2_000_000_000_000, 50), 10);
+
+        // Double the reference volatility halves the cap, down to the floor scale
+        pair_config.reference_volatility_bps = 100;
+        pair_config.volatility_bps = 200;
+        pair_config.min_leverage_scale_bps = 1_000;
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 50), 15);
+        pair_config.volatility_bps = 100_000;
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 50), 3);
+
+        // Scaling never goes below 1x
+        pair_config.max_leverage = 2;
+        assert_eq!(pair_config.max_leverage_for(1_000_000_000, 50), 1);
+        assert!(pair_config.require_leverage(2, 1_000_000_000, 50).is_err());
+    }
+
+    fn breaker_pair_config() -> PairConfig {
+        // 2% limit over 5 minutes, 10 minute cool-down, last accepted at t=1_000
+        let mut pair_config = pair_config();
+        pair_config.breaker_max_move_bps = 200;
+        pair_config.breaker_window_secs = 300;
+        pair_config.breaker_cooldown_secs = 600;
+        pair_config.last_accepted_price = 100_000_000;
+        pair_config.last_accepted_at = 1_000;
+        pair_config
+    }
+
+    #[test]
+    fn test_breaker_move_exceeded() {
+        let mut pair_config = breaker_pair_config();
+
+        // Within the limit, at the limit and beyond it inside the window
+        assert!(!pair_config.breaker_move_exceeded(101_000_000, 1_100));
+        assert!(!pair_config.breaker_move_exceeded(98_000_000, 1_100));
+        assert!(pair_config.breaker_move_exceeded(97_000_000, 1_100));
+
+        // Once the reference is older than the window any move is accepted
+        assert!(!pair_config.breaker_move_exceeded(50_000_000, 1_301));
+
+        // No reference yet, or the breaker disabled
+        pair_config.last_accepted_price = 0;
+        assert!(!pair_config.breaker_move_exceeded(50_000_000, 1_100));
+        let mut disabled = breaker_pair_config();
+        disabled.breaker_max_move_bps = 0;
+        assert!(!disabled.breaker_move_exceeded(50_000_000, 1_100));
+    }
+
+    #[test]
+    fn test_screen_price() {
+        let mut pair_config = breaker_pair_config();
+
+        // A normal tick becomes the new reference
+        assert!(pair_config.screen_price(101_000_000, 1_100).unwrap());
+        assert_eq!(pair_config.last_accepted_price, 101_000_000);
+        assert_eq!(pair_config.last_accepted_at, 1_100);
+
+        // A bad tick trips the breaker without moving the reference
+        assert!(!pair_config.screen_price(90_000_000, 1_200).unwrap());
+        assert_eq!(pair_config.breaker_tripped_until, 1_800);
+        assert_eq!(pair_config.last_accepted_price, 101_000_000);
+
+        // Screening fails during the cool-down and resumes after it
+        assert!(pair_config.in_breaker_cooldown(1_799));
+        assert!(pair_config.screen_price(101_000_000, 1_500).is_err());
+        assert!(pair_config.screen_price(90_000_000, 1_800).unwrap());
+        assert_eq!(pair_config.last_accepted_price, 90_000_000);
+    }
+
+    #[test]
+    fn test_accept_price() {
+        let mut pair_config = breaker_pair_config();
+
+        assert!(pair_config.accept_price(101_000_000, 1_100).is_ok());
+        assert_eq!(pair_config.last_accepted_price, 101_000_000);
+
+        // Rejecting a bad tick leaves the reference alone and starts no cool-down
+        assert!(pair_config.accept_price(90_000_000, 1_200).is_err());
+        assert_eq!(pair_config.last_accepted_price, 101_000_000);
+        assert_eq!(pair_config.breaker_tripped_until, 0);
+
+        // Nothing is accepted during a cool-down
+        pair_config.breaker_tripped_until = 2_000;
+        assert!(pair_config.accept_price(101_000_000, 1_300).is_err());
+        assert!(pair_config.accept_price(101_000_000, 2_000).is_ok());
+    }
+
+    #[test]
+    fn test_settlement_price_frozen_after_first_settlement() {
+        let mut pair_config = pair_config();
+
+        assert!(pair_config.set_settlement_price(0, -5).is_err());
+        assert!(pair_config.set_settlement_price(108_000, -5).is_ok());
+        // Governance can still correct the price before anything settles
+        assert!(pair_config.set_settlement_price(108_500, -5).is_ok());
+
+        pair_config.settlement_price_frozen = true;
+        assert!(pair_config.set_settlement_price(109_000, -5).is_err());
+        assert_eq!(pair_config.settlement_price, 108_500);
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use super::{FxPair, Direction, StablecoinType};
+use super::{FxPair, Direction, StablecoinType, QuoteConversion};
+use crate::utils::{scale_by_price_ratio, calculate_trigger_prices};
 
 /// Represents a leveraged FX position with auto-deleverage support
 #[account]
@@ -51,6 +52,10 @@ pub struct Position {
     /// Track which deleverage tiers have been executed
     pub deleverage_executed: [bool; 4],
 
+    /// Timestamp each tier's trigger was first observed crossed (0 if not crossed)
+    /// Drives the Dutch-auction keeper reward in trigger_deleverage
+    pub trigger_crossed_at: [i64; 4],
+
     /// Timestamp when position was opened
     pub opened_at: i64,
 
@@ -62,6 +67,25 @@ pub struct Position {
 
     /// Bump for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Margin is allocated from the owner's CrossMarginAccount rather than held in isolation
+    pub cross_margin: bool,
+
+    /// Quote conversion oracle price at entry (cross pairs only, 0 otherwise or if opened before)
+    /// See FxPair::quote_conversion
+    pub entry_quote_price: i64,
+
+    /// Quote conversion oracle price exponent
+    pub entry_quote_price_expo: i32,
+
+    /// PnL already realized by partial deleverage tiers (USDC, 6 decimals)
+    pub realized_pnl: i64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 43],
 }
 
 impl Position {
@@ -81,18 +105,33 @@ impl Position {
         4 +                       // entry_price_expo
         32 +                      // trigger_prices (4 * 8)
         4 +                       // deleverage_executed (4 * 1)
+        32 +                      // trigger_crossed_at (4 * 8)
         8 +                       // opened_at
         9 +                       // closed_at (Option<i64>)
         9 +                       // final_pnl_otus (Option<i64>)
-        1;                        // bump
+        1 +                       // bump
+        1 +                       // version
+        1 +                       // cross_margin
+        8 +                       // entry_quote_price
+        4 +                       // entry_quote_price_expo
+        8 +                       // realized_pnl
+        43;                       // reserved
 
     pub const SEED_PREFIX: &'static [u8] = b"position";
 
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
     /// Calculate current margin health percentage (0-100)
     /// margin_health = (margin + unrealized_pnl) / initial_margin * 100
-    pub fn calculate_margin_health(&self, current_price: i64, current_price_expo: i32) -> Result<u8> {
+    pub fn calculate_margin_health(
+        &self,
+        current_price: i64,
+        current_price_expo: i32,
+        quote_price: Option<(i64, i32)>,
+    ) -> Result<u8> {
         // Calculate unrealized PnL
-        let pnl = self.calculate_unrealized_pnl(current_price, current_price_expo)?;
+        let pnl = self.calculate_unrealized_pnl(current_price, current_price_expo, quote_price)?;
 
         // Current equity = margin + unrealized PnL
         let current_equity = if pnl >= 0 {
@@ -114,7 +153,13 @@ impl Position {
     }
 
     /// Calculate unrealized PnL in USDC (can be negative)
-    pub fn calculate_unrealized_pnl(&self, current_price: i64, current_price_expo: i32) -> Result<i64> {
+    /// `quote_price` is the current quote conversion oracle price, required for cross pairs
+    pub fn calculate_unrealized_pnl(
+        &self,
+        current_price: i64,
+        current_price_expo: i32,
+        quote_price: Option<(i64, i32)>,
+    ) -> Result<i64> {
         // Normalize prices to same exponent
         let (entry_normalized, current_normalized) = if self.entry_price_expo == current_price_expo {
             (self.entry_price, current_price)
@@ -147,7 +192,39 @@ impl Position {
             .checked_div(entry_normalized as i128)
             .ok_or(error!(crate::error::TradingError::DivisionByZero))? as i64;
 
-        Ok(pnl)
+        self.quote_pnl_to_usd(pnl, current_price, current_price_expo, quote_price)
+    }
+
+    /// Revalue a PnL computed at entry-time quote rates by the quote currency's move against USD
+    fn quote_pnl_to_usd(
+        &self,
+        pnl: i64,
+        current_price: i64,
+        current_price_expo: i32,
+        quote_price: Option<(i64, i32)>,
+    ) -> Result<i64> {
+        match self.pair.quote_conversion() {
+            QuoteConversion::Usd => Ok(pnl),
+            QuoteConversion::InversePair => scale_by_price_ratio(
+                pnl,
+                (self.entry_price, self.entry_price_expo),
+                (current_price, current_price_expo),
+            ),
+            QuoteConversion::Oracle { inverse, .. } => {
+                // Positions opened before conversion was recorded keep entry-time quote rates
+                if self.entry_quote_price == 0 {
+                    return Ok(pnl);
+                }
+                let current = quote_price
+                    .ok_or(error!(crate::error::TradingError::MissingQuoteOracle))?;
+                let entry = (self.entry_quote_price, self.entry_quote_price_expo);
+                if inverse {
+                    scale_by_price_ratio(pnl, entry, current)
+                } else {
+                    scale_by_price_ratio(pnl, current, entry)
+                }
+            }
+        }
     }
 
     /// Check if a deleverage tier should be triggered
@@ -160,6 +237,38 @@ impl Position {
         current_margin_health <= threshold && !self.deleverage_executed[tier as usize]
     }
 
+    /// Trigger price for a tier under the current (volatility-scaled) thresholds
+    /// Whichever of this and the trigger stored at open is reached first applies
+    pub fn effective_trigger_price(&self, tier: u8, thresholds: &[u8; 4]) -> Result<i64> {
+        let stored = self.trigger_prices[tier as usize];
+        let current = calculate_trigger_prices(
+            self.entry_price,
+            self.direction,
+            self.pair.quote_conversion() == QuoteConversion::InversePair,
+            thresholds,
+        )?[tier as usize];
+
+        Ok(match self.direction {
+            Direction::Long => stored.max(current),
+            Direction::Short => stored.min(current),
+        })
+    }
+
+    /// Add PnL realized by a deleverage tier to the running total
+    pub fn realize_pnl(&mut self, pnl: i64) -> Result<()> {
+        self.realized_pnl = self.realized_pnl
+            .checked_add(pnl)
+            .ok_or(error!(crate::error::TradingError::ArithmeticOverflow))?;
+        Ok(())
+    }
+
+    /// Lifetime realized PnL once the remaining size closes with `closing_pnl`
+    pub fn lifetime_pnl(&self, closing_pnl: i64) -> Result<i64> {
+        self.realized_pnl
+            .checked_add(closing_pnl)
+            .ok_or(error!(crate::error::TradingError::ArithmeticOverflow))
+    }
+
     /// Calculate how much position to close for a deleverage tier
     pub fn calculate_deleverage_amount(&self, tier: u8) -> Result<(u64, u8)> {
         let (close_percentage, target_leverage) = match tier {
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Referral code registered by a referrer
+/// Traders bind to a code once; a share of their trading fees accrues here for the referrer
+#[account]
+pub struct ReferralCode {
+    /// Code chosen by the referrer (PDA seed, zero-padded)
+    pub code: [u8; 16],
+
+    /// Referrer credited with the fee share
+    pub referrer: Pubkey,
+
+    /// Number of trader accounts bound to this code
+    pub referred_count: u64,
+
+    /// Referrer's unclaimed fee share (USDC, 6 decimals)
+    pub claimable: u64,
+
+    /// Lifetime fee share credited to the referrer (USDC, 6 decimals)
+    pub total_earned: u64,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl ReferralCode {
+    pub const LEN: usize = 8 +   // discriminator
+        16 +                      // code
+        32 +                      // referrer
+        8 +                       // referred_count
+        8 +                       // claimable
+        8 +                       // total_earned
+        1 +                       // bump
+        1 +                       // version
+        32;                       // reserved
+
+    pub const SEED_PREFIX: &'static [u8] = b"referral";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+}
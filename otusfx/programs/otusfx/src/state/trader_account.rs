@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::error::TradingError;
+use crate::utils::{calculate_rolling_volume, roll_volume_buckets};
+
+/// Per-trader index of open positions
+/// Lets clients enumerate a trader's positions without scanning program accounts
+#[account]
+pub struct TraderAccount {
+    /// Trader this index belongs to
+    pub owner: Pubkey,
+
+    /// IDs of the trader's open positions (bounded by MAX_OPEN_POSITIONS)
+    pub open_position_ids: Vec<u64>,
+
+    /// Lifetime number of positions opened
+    pub total_opened: u64,
+
+    /// Lifetime number of positions closed (manually, settled or fully deleveraged)
+    pub total_closed: u64,
+
+    /// Hash chain over every serialized PositionClosed record (zero before the first close)
+    /// Lets off-chain history be verified against the chain after the positions are gone
+    pub history_hash: [u8; 32],
+
+    /// Cumulative realized PnL of closed positions (USDC, 6 decimals)
+    pub realized_pnl: i64,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see migrate instructions)
+    pub version: u8,
+
+    /// Notional volume per day over the rolling window, indexed by day % VOLUME_WINDOW_DAYS
+    pub volume_buckets: [u64; 30],
+
+    /// Most recent day (unix_timestamp / SECONDS_PER_DAY) written to volume_buckets
+    pub volume_day: i64,
+
+    /// Referral code this trader is bound to (default pubkey if none; set once)
+    pub referral_code: Pubkey,
+
+    /// Unclaimed fee rebate from trading under a referral code (USDC, 6 decimals)
+    pub claimable_rebate: u64,
+
+    /// Unclaimed margin left on positions closed by the final deleverage tier (USDC, 6 decimals)
+    pub claimable_margin: u64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl TraderAccount {
+    /// Hard cap on tracked open positions (sizes the account)
+    pub const MAX_OPEN_POSITIONS: usize = 32;
+
+    pub const LEN: usize = 8 +   // discriminator
+        32 +                      // owner
+        4 + 8 * Self::MAX_OPEN_POSITIONS + // open_position_ids
+        8 +                       // total_opened
+        8 +                       // total_closed
+        32 +                      // history_hash
+        8 +                       // realized_pnl
+        1 +                       // bump
+        1 +                       // version
+        8 * Self::VOLUME_WINDOW_DAYS + // volume_buckets
+        8 +                       // volume_day
+        32 +                      // referral_code
+        8 +                       // claimable_rebate
+        8 +                       // claimable_margin
+        16;                       // reserved
+
+    pub const SEED_PREFIX: &'static [u8] = b"trader";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+
+    /// Length of the rolling volume window used for fee tiers
+    pub const VOLUME_WINDOW_DAYS: usize = 30;
+
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    /// Notional volume over the rolling window ending at `now`
+    pub fn rolling_volume(&self, now: i64) -> u64 {
+        calculate_rolling_volume(&self.volume_buckets, self.volume_day, now / Self::SECONDS_PER_DAY)
+    }
+
+    /// Add traded notional to today's bucket
+    pub fn record_volume(&mut self, notional: u64, now: i64) {
+        let day = now / Self::SECONDS_PER_DAY;
+        roll_volume_buckets(&mut self.volume_buckets, self.volume_day, day);
+        self.volume_day = self.volume_day.max(day);
+
+        let index = self.volume_day.rem_euclid(Self::VOLUME_WINDOW_DAYS as i64) as usize;
+        self.volume_buckets[index] = self.volume_buckets[index].saturating_add(notional);
+    }
+
+    /// Whether the trader has bound a referral code
+    pub fn has_referral(&self) -> bool {
+        self.referral_code != Pubkey::default()
+    }
+
+    /// Record a newly opened position, enforcing the per-trader limit
+    pub fn add_position(&mut self, position_id: u64, max_open_positions: u8) -> Result<()> {
+        require!(
+            self.open_position_ids.len() < (max_open_positions as usize).min(Self::MAX_OPEN_POSITIONS),
+            TradingError::TooManyOpenPositions
+        );
+
+        self.open_position_ids.push(position_id);
+        self.total_opened = self.total_opened
+            .checked_add(1)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Drop a closed position from the index and fold its final record into the history
+    /// Positions opened before the index existed are not tracked and are only recorded
+    pub fn record_close(&mut self, position_id: u64, record: &[u8], realized_pnl: i64) -> Result<()> {
+        if let Some(index) = self.open_position_ids.iter().position(|&id| id == position_id) {
+            self.open_position_ids.swap_remove(index);
+        }
+
+        self.total_closed = self.total_closed
+            .checked_add(1)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        self.realized_pnl = self.realized_pnl
+            .checked_add(realized_pnl)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        self.history_hash = hashv(&[&self.history_hash, record]).to_bytes();
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, CrossMarginAccount, Position, PairConfig, FxPair, Direction, Currency, QuoteConversion};
+use super::{get_cached_price, get_quote_conversion_price, calculate_cross_margin_health, calculate_portfolio_margin, PYTH_ORACLE_PROGRAM_ID};
+
+/// Maximum staleness in slots (~5 minutes at 400ms/slot)
+const MAX_STALENESS_SLOTS: u64 = 750;
+
+/// A cross position marked to its pair's oracle price
+pub struct CrossPositionMark {
+    pub position_id: u64,
+    pub pair: FxPair,
+    pub direction: Direction,
+    pub size: u64,
+    pub unrealized_pnl: i64,
+    pub deleverage_executed: [bool; 4],
+}
+
+/// Mark every open position of a cross-margin account
+///
+/// `accounts` holds a (position, pair config, price cache) group per position, followed by
+/// the quote conversion price cache for cross pairs (see FxPair::quote_conversion). Each
+/// price cache must be the feed bound to the position's pair config. Every one of the
+/// account's positions must be listed exactly once, so health can't be computed over a
+/// favourable subset.
+pub fn mark_cross_positions(
+    cross_margin: &CrossMarginAccount,
+    accounts: &[AccountInfo],
+    max_confidence_bps: u16,
+    clock: &Clock,
+) -> Result<Vec<CrossPositionMark>> {
+    let mut marks: Vec<CrossPositionMark> = Vec::with_capacity(cross_margin.position_ids.len());
+    let mut remaining = accounts.iter();
+    while let Some(position_info) = remaining.next() {
+        require!(
+            position_info.owner == &crate::ID,
+            TradingError::InvalidCrossMarginPositions
+        );
+        let position = Position::try_deserialize(&mut &position_info.try_borrow_data()?[..])?;
+
+        require!(
+            position.owner == cross_margin.owner
+                && position.cross_margin
+                && position.is_open
+                && cross_margin.position_ids.contains(&position.position_id)
+                && !marks.iter().any(|mark| mark.position_id == position.position_id),
+            TradingError::InvalidCrossMarginPositions
+        );
+
+        // Only the program creates pair configs, one per pair
+        let pair_config_info = remaining.next().ok_or(TradingError::InvalidCrossMarginPositions)?;
+        require!(
+            pair_config_info.owner == &crate::ID,
+            TradingError::InvalidCrossMarginPositions
+        );
+        let pair_config = PairConfig::try_deserialize(&mut &pair_config_info.try_borrow_data()?[..])?;
+        require!(
+            pair_config.pair == position.pair,
+            TradingError::InvalidCrossMarginPositions
+        );
+
+        let price_cache = remaining.next().ok_or(TradingError::InvalidCrossMarginPositions)?;
+        require!(
+            price_cache.key() == pair_config.price_feed && price_cache.owner == &PYTH_ORACLE_PROGRAM_ID,
+            TradingError::InvalidOracleAccount
+        );
+        let (price, price_expo) = get_cached_price(
+            price_cache,
+            position.pair,
+            MAX_STALENESS_SLOTS,
+            max_confidence_bps,
+            clock,
+        )?;
+
+        let quote_price_cache = match position.pair.quote_conversion() {
+            QuoteConversion::Oracle { .. } => Some(
+                remaining.next().ok_or(TradingError::MissingQuoteOracle)?,
+            ),
+            _ => None,
+        };
+        let quote_price = get_quote_conversion_price(
+            &pair_config,
+            quote_price_cache,
+            MAX_STALENESS_SLOTS,
+            max_confidence_bps,
+            clock,
+        )?;
+
+        marks.push(CrossPositionMark {
+            position_id: position.position_id,
+            pair: position.pair,
+            direction: position.direction,
+            size: position.size,
+            unrealized_pnl: position.calculate_unrealized_pnl(price, price_expo, quote_price)?,
+            deleverage_executed: position.deleverage_executed,
+        });
+    }
+
+    require!(
+        marks.len() == cross_margin.position_ids.len(),
+        TradingError::InvalidCrossMarginPositions
+    );
+
+    Ok(marks)
+}
+
+/// Net USD-valued exposure per currency, indexed by Currency
+///
+/// Each position is split into a base leg and an opposite quote leg, both worth its notional.
+/// PnL is realized in USD, the collateral currency, so it carries no currency risk; USD legs
+/// are left out for the same reason and the USD entry is always zero.
+pub fn net_currency_exposure(marks: &[CrossPositionMark]) -> [i128; Currency::COUNT] {
+    let mut exposures = [0i128; Currency::COUNT];
+    for mark in marks {
+        let (base, quote) = mark.pair.currencies();
+        let size = mark.size as i128;
+        let (base_leg, quote_leg) = match mark.direction {
+            Direction::Long => (size, -size),
+            Direction::Short => (-size, size),
+        };
+        exposures[base as usize] += base_leg;
+        exposures[quote as usize] += quote_leg;
+    }
+    exposures[Currency::USD as usize] = 0;
+    exposures
+}
+
+/// Margin the account's positions require: allocated margin, or the netted requirement in portfolio mode
+///
+/// The netted requirement is floored at portfolio_margin_floor_bps of the gross notional,
+/// so a fully offset book still carries margin.
+pub fn cross_margin_requirement(
+    cross_margin: &CrossMarginAccount,
+    marks: &[CrossPositionMark],
+    config: &TradingConfig,
+) -> u64 {
+    if cross_margin.portfolio_margin {
+        let gross_notional = marks.iter().map(|mark| mark.size as u128).sum();
+        calculate_portfolio_margin(
+            &net_currency_exposure(marks),
+            &config.currency_risk_weights_bps,
+            gross_notional,
+            config.portfolio_margin_floor_bps,
+        )
+    } else {
+        cross_margin.allocated_margin
+    }
+}
+
+/// Health of a cross-margin account over its marked positions
+pub fn cross_margin_health(
+    cross_margin: &CrossMarginAccount,
+    marks: &[CrossPositionMark],
+    config: &TradingConfig,
+) -> Result<u8> {
+    let unrealized_pnl = marks
+        .iter()
+        .try_fold(0i64, |total, mark| total.checked_add(mark.unrealized_pnl))
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    Ok(calculate_cross_margin_health(
+        cross_margin.collateral,
+        unrealized_pnl,
+        cross_margin_requirement(cross_margin, marks, config),
+    ))
+}
+
+/// Whether a position is the largest-notional one still eligible for a deleverage tier
+pub fn is_largest_risk(marks: &[CrossPositionMark], position_id: u64, tier: u8) -> bool {
+    let eligible = || marks.iter().filter(|mark| !mark.deleverage_executed[tier as usize]);
+    let Some(target) = eligible().find(|mark| mark.position_id == position_id) else {
+        return false;
+    };
+    eligible().all(|mark| mark.size <= target.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::calculate_portfolio_margin;
+
+    fn mark(pair: FxPair, direction: Direction, size: u64, unrealized_pnl: i64) -> CrossPositionMark {
+        CrossPositionMark {
+            position_id: 0,
+            pair,
+            direction,
+            size,
+            unrealized_pnl,
+            deleverage_executed: [false; 4],
+        }
+    }
+
+    #[test]
+    fn test_net_currency_exposure_excludes_usd() {
+        // PnL is USD and never lands on a currency leg
+        let exposures = net_currency_exposure(&[mark(FxPair::EURUSD, Direction::Long, 1_000, 50)]);
+        assert_eq!(exposures[Currency::EUR as usize], 1_000);
+        assert_eq!(exposures[Currency::USD as usize], 0);
+
+        // Inverse pairs: the USD base leg is dropped and only the JPY leg remains
+        let exposures = net_currency_exposure(&[mark(FxPair::USDJPY, Direction::Long, 1_000, -30)]);
+        assert_eq!(exposures[Currency::JPY as usize], -1_000);
+        assert_eq!(exposures[Currency::USD as usize], 0);
+    }
+
+    #[test]
+    fn test_offsetting_pairs_need_less_margin_than_isolated() {
+        let weights = [0, 300, 300, 400, 500, 500, 500, 500];
+        let size = 1_000_000_000; // $1,000 per leg
+        let max_leverage = 20;
+
+        // EURUSD long against GBPUSD short: the USD legs cancel, leaving EUR and GBP risk
+        let marks = [
+            mark(FxPair::EURUSD, Direction::Long, size, 0),
+            mark(FxPair::GBPUSD, Direction::Short, size, 0),
+        ];
+        let portfolio = calculate_portfolio_margin(&net_currency_exposure(&marks), &weights, 2 * size as u128, 100);
+        assert_eq!(portfolio, 60_000_000);
+
+        // The same legs margined separately, even at the maximum leverage
+        let isolated = 2 * size / max_leverage;
+        assert!(portfolio < isolated);
+    }
+}
@@ -1,6 +1,28 @@
 use anchor_lang::prelude::*;
 use crate::error::TradingError;
 
+/// Scale a value by the ratio of two prices, normalizing their exponents
+pub fn scale_by_price_ratio(value: i64, numerator: (i64, i32), denominator: (i64, i32)) -> Result<i64> {
+    let (mut num, num_expo) = (numerator.0 as i128, numerator.1);
+    let (mut den, den_expo) = (denominator.0 as i128, denominator.1);
+    if num_expo > den_expo {
+        num = num
+            .checked_mul(10_i128.pow((num_expo - den_expo) as u32))
+            .ok_or(error!(TradingError::ArithmeticOverflow))?;
+    } else if den_expo > num_expo {
+        den = den
+            .checked_mul(10_i128.pow((den_expo - num_expo) as u32))
+            .ok_or(error!(TradingError::ArithmeticOverflow))?;
+    }
+
+    let scaled = (value as i128)
+        .checked_mul(num)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_div(den)
+        .ok_or(error!(TradingError::DivisionByZero))?;
+    i64::try_from(scaled).map_err(|_| error!(TradingError::ArithmeticOverflow))
+}
+
 /// Calculate trading fee in USDC
 pub fn calculate_trading_fee(notional: u64, fee_bps: u16) -> Result<u64> {
     let fee = (notional as u128)
@@ -11,21 +33,235 @@ pub fn calculate_trading_fee(notional: u64, fee_bps: u16) -> Result<u64> {
     Ok(fee)
 }
 
+/// Reduce a fee by a discount in basis points (10_000 = free)
+pub fn apply_fee_discount(fee: u64, discount_bps: u16) -> Result<u64> {
+    let discount = (fee as u128)
+        .checked_mul(discount_bps.min(10_000) as u128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_div(10_000)
+        .ok_or(error!(TradingError::DivisionByZero))? as u64;
+    Ok(fee - discount)
+}
+
+/// Combine the Scops NFT and volume tier discounts
+/// Discounts do not stack: the larger of the two applies
+pub fn combine_fee_discounts(scops_discount_bps: u16, volume_discount_bps: u16) -> u16 {
+    scops_discount_bps.max(volume_discount_bps)
+}
+
+/// Split a charged fee into (protocol, referrer, rebate) shares
+/// Rounding dust stays with the protocol
+pub fn split_referral_fee(fee: u64, share_bps: u16, rebate_bps: u16) -> Result<(u64, u64, u64)> {
+    let referrer = calculate_trading_fee(fee, share_bps)?;
+    let rebate = calculate_trading_fee(fee, rebate_bps)?;
+    let protocol = fee
+        .checked_sub(referrer)
+        .and_then(|rest| rest.checked_sub(rebate))
+        .ok_or(TradingError::ArithmeticUnderflow)?;
+    Ok((protocol, referrer, rebate))
+}
+
+/// Advance daily volume buckets from `last_day` to `day`, clearing days that left the window
+pub fn roll_volume_buckets(buckets: &mut [u64], last_day: i64, day: i64) {
+    let window = buckets.len() as i64;
+    if day <= last_day {
+        return;
+    }
+    if day - last_day >= window {
+        buckets.fill(0);
+        return;
+    }
+
+    for d in (last_day + 1)..=day {
+        buckets[d.rem_euclid(window) as usize] = 0;
+    }
+}
+
+/// Sum the bucketed volume of the window ending at `day`
+/// Buckets are only current up to `last_day`; later days have no volume yet
+pub fn calculate_rolling_volume(buckets: &[u64], last_day: i64, day: i64) -> u64 {
+    let window = buckets.len() as i64;
+    let day = day.max(last_day);
+    if day - last_day >= window {
+        return 0;
+    }
+
+    ((day - window + 1)..=last_day)
+        .map(|d| buckets[d.rem_euclid(window) as usize])
+        .fold(0u64, |total, volume| total.saturating_add(volume))
+}
+
+/// Cross-margin health percentage: (collateral + unrealized PnL) / allocated margin * 100
+/// An account with nothing allocated is fully healthy
+pub fn calculate_cross_margin_health(collateral: u64, unrealized_pnl: i64, allocated_margin: u64) -> u8 {
+    if allocated_margin == 0 {
+        return u8::MAX;
+    }
+
+    let equity = (collateral as i128 + unrealized_pnl as i128).max(0);
+    let health = equity * 100 / allocated_margin as i128;
+    health.min(u8::MAX as i128) as u8
+}
+
+/// Portfolio margin requirement: each currency's absolute net exposure times its risk weight,
+/// but never less than floor_bps of the gross notional
+pub fn calculate_portfolio_margin(
+    net_exposures: &[i128],
+    risk_weights_bps: &[u16],
+    gross_notional: u128,
+    floor_bps: u16,
+) -> u64 {
+    let netted: u128 = net_exposures
+        .iter()
+        .zip(risk_weights_bps.iter())
+        .map(|(exposure, &weight)| exposure.unsigned_abs() * weight as u128 / 10_000)
+        .sum();
+    let floor = gross_notional * floor_bps as u128 / 10_000;
+    netted.max(floor).min(u64::MAX as u128) as u64
+}
+
 /// Calculate keeper reward (percentage of closed notional, not margin)
-pub fn calculate_keeper_reward(closed_notional: u64, keeper_fee_bps: u16) -> Result<u64> {
+/// The result is bounded by the tier's [min_reward, max_reward] range
+pub fn calculate_keeper_reward(
+    closed_notional: u64,
+    keeper_fee_bps: u16,
+    min_reward: u64,
+    max_reward: u64,
+) -> Result<u64> {
     let reward = (closed_notional as u128)
         .checked_mul(keeper_fee_bps as u128)
         .ok_or(error!(TradingError::ArithmeticOverflow))?
         .checked_div(10_000)
         .ok_or(error!(TradingError::DivisionByZero))? as u64;
-    Ok(reward)
+    Ok(reward.max(min_reward).min(max_reward))
+}
+
+/// Calculate the Dutch-auction keeper fee for a trigger crossed `elapsed_secs` ago
+/// Grows linearly from start_bps to max_bps over ramp_secs, then stays at max_bps
+pub fn calculate_auction_fee_bps(
+    start_bps: u16,
+    max_bps: u16,
+    ramp_secs: i64,
+    elapsed_secs: i64,
+) -> u16 {
+    if start_bps >= max_bps || ramp_secs <= 0 || elapsed_secs >= ramp_secs {
+        return max_bps;
+    }
+    if elapsed_secs <= 0 {
+        return start_bps;
+    }
+
+    let range = (max_bps - start_bps) as i64;
+    start_bps + (range * elapsed_secs / ramp_secs) as u16
+}
+
+/// Split a keeper reward between the position's margin and the insurance fund
+/// Returns (from_margin, from_insurance); the sum may be less than the reward
+/// if both sources are exhausted
+pub fn split_keeper_reward(reward: u64, margin: u64, insurance_fund: u64) -> (u64, u64) {
+    let from_margin = reward.min(margin);
+    let from_insurance = (reward - from_margin).min(insurance_fund);
+    (from_margin, from_insurance)
+}
+
+/// Split the margin left on a position closed by the final deleverage tier
+/// Returns (to_insurance, to_owner): the liquidation penalty, capped by the margin, goes to
+/// the insurance fund and the rest still belongs to the owner
+pub fn split_liquidation_margin(margin: u64, liquidation_penalty: u64) -> (u64, u64) {
+    let to_insurance = liquidation_penalty.min(margin);
+    (to_insurance, margin - to_insurance)
+}
+
+/// Calculate the pro-rata payout ratio for global settlement (10_000 = no haircut)
+/// Margin claims are paid in full when the vault covers them, otherwise scaled down evenly
+pub fn calculate_settlement_payout_bps(vault_balance: u64, total_open_margin: u64) -> u16 {
+    if total_open_margin == 0 || vault_balance >= total_open_margin {
+        return 10_000;
+    }
+
+    ((vault_balance as u128) * 10_000 / (total_open_margin as u128)) as u16
+}
+
+/// Apply a settlement payout ratio to a margin claim
+pub fn apply_settlement_haircut(margin: u64, payout_bps: u16) -> Result<u64> {
+    let payout = (margin as u128)
+        .checked_mul(payout_bps as u128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_div(10_000)
+        .ok_or(error!(TradingError::DivisionByZero))? as u64;
+    Ok(payout)
+}
+
+/// Calculate how far the price must move to reach a target, in basis points of the current price
+pub fn calculate_price_distance_bps(current_price: i64, target_price: i64) -> u64 {
+    if current_price <= 0 {
+        return 0;
+    }
+
+    let distance = (current_price as i128 - target_price as i128).unsigned_abs();
+    (distance * 10_000 / current_price as u128).min(u64::MAX as u128) as u64
+}
+
+/// Volatility sample in bps of price: the move since the last sample (capped at max_move_bps)
+/// plus the oracle confidence
+/// The first sample (no previous price) is confidence only
+pub fn calculate_volatility_sample_bps(last_price: i64, price: i64, conf: u64, max_move_bps: u64) -> Result<u32> {
+    require!(price > 0, TradingError::InvalidOracleAccount);
+
+    let move_bps = if last_price > 0 {
+        calculate_price_distance_bps(last_price, price).min(max_move_bps)
+    } else {
+        0
+    };
+    let conf_bps = conf as u128 * 10_000 / price as u128;
+    Ok((move_bps as u128 + conf_bps).min(u32::MAX as u128) as u32)
+}
+
+/// Current volatility relative to the reference (bps, 10_000 = at reference; 10_000 if disabled)
+pub fn calculate_volatility_ratio_bps(volatility_bps: u32, reference_bps: u32) -> u32 {
+    if reference_bps == 0 {
+        return 10_000;
+    }
+    (volatility_bps as u64 * 10_000 / reference_bps as u64).min(u32::MAX as u64) as u32
+}
+
+/// Execution price for a trade against the pair's open interest skew
+/// The premium is the average skew over the trade divided by `skew_scale`, capped at `max_impact_bps`
+/// `trade_notional` is signed (positive buys the base currency); `skew_scale` 0 returns the oracle price
+pub fn calculate_execution_price(
+    oracle_price: i64,
+    skew: i128,
+    trade_notional: i128,
+    skew_scale: u64,
+    max_impact_bps: u16,
+) -> Result<i64> {
+    if skew_scale == 0 {
+        return Ok(oracle_price);
+    }
+
+    // price * (skew_before + skew_after) / 2 / skew_scale
+    let skew_sum = skew
+        .checked_mul(2)
+        .and_then(|doubled| doubled.checked_add(trade_notional))
+        .ok_or(error!(TradingError::ArithmeticOverflow))?;
+    let premium = (oracle_price as i128)
+        .checked_mul(skew_sum)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_div(2 * skew_scale as i128)
+        .ok_or(error!(TradingError::DivisionByZero))?;
+    let max_premium = oracle_price as i128 * max_impact_bps as i128 / 10_000;
+
+    Ok((oracle_price as i128 + premium.clamp(-max_premium, max_premium)) as i64)
 }
 
 /// Calculate trigger prices for auto-deleverage tiers
 /// Returns array of 4 trigger prices [tier0, tier1, tier2, tier3]
+/// `usd_base` pairs (USDJPY, USDCAD, USDCHF) realize PnL in the quote currency; their USD loss
+/// on notional is (entry - price) / price rather than (entry - price) / entry, so triggers are inverted
 pub fn calculate_trigger_prices(
     entry_price: i64,
     direction: crate::state::Direction,
+    usd_base: bool,
     thresholds: &[u8; 4], // [50, 35, 25, 15]
 ) -> Result<[i64; 4]> {
     use crate::state::Direction;
@@ -53,6 +289,21 @@ pub fn calculate_trigger_prices(
         // With leverage, this scales inversely
         let price_move_bps = loss_pct as i64 * 100; // Convert to basis points
 
+        if usd_base {
+            // Long: loss = (entry - p) / p  =>  p = entry / (1 + loss)
+            // Short: loss = (p - entry) / p  =>  p = entry / (1 - loss)
+            let denominator_bps = match direction {
+                Direction::Long => 10_000 + price_move_bps,
+                Direction::Short => 10_000 - price_move_bps,
+            };
+            triggers[i] = (entry_price as i128)
+                .checked_mul(10_000)
+                .ok_or(error!(TradingError::ArithmeticOverflow))?
+                .checked_div(denominator_bps as i128)
+                .ok_or(error!(TradingError::DivisionByZero))? as i64;
+            continue;
+        }
+
         let trigger_price = match direction {
             Direction::Long => {
                 // For long, trigger is below entry (loss when price drops)
@@ -82,6 +333,41 @@ pub fn calculate_trigger_prices(
     Ok(triggers)
 }
 
+/// Blend the entry price when adding notional to an existing position
+/// Keeps unrealized PnL unchanged: new_size / entry' = size / entry + added / price
+pub fn calculate_blended_entry_price(
+    size: u64,
+    entry_price: i64,
+    added_size: u64,
+    current_price: i64,
+) -> Result<i64> {
+    let total_size = (size as i128)
+        .checked_add(added_size as i128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?;
+
+    // entry' = total_size * entry * price / (size * price + added * entry)
+    let numerator = total_size
+        .checked_mul(entry_price as i128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_mul(current_price as i128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?;
+    let denominator = (size as i128)
+        .checked_mul(current_price as i128)
+        .ok_or(error!(TradingError::ArithmeticOverflow))?
+        .checked_add(
+            (added_size as i128)
+                .checked_mul(entry_price as i128)
+                .ok_or(error!(TradingError::ArithmeticOverflow))?,
+        )
+        .ok_or(error!(TradingError::ArithmeticOverflow))?;
+
+    let blended = numerator
+        .checked_div(denominator)
+        .ok_or(error!(TradingError::DivisionByZero))?;
+
+    i64::try_from(blended).map_err(|_| error!(TradingError::ArithmeticOverflow))
+}
+
 /// Convert USDC amount to OTUS amount (simple ratio for MVP)
 /// In production, this would query the actual OTUS/USDC price from treasury
 pub fn usdc_to_otus(usdc_amount: u64, otus_price_usdc: u64) -> Result<u64> {
@@ -105,3 +391,186 @@ pub fn otus_to_usdc(otus_amount: u64, otus_price_usdc: u64) -> Result<u64> {
         .ok_or(error!(TradingError::DivisionByZero))? as u64;
     Ok(usdc)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeper_reward_bounds() {
+        // 5 bps of 10,000 USDC = 5 USDC
+        assert_eq!(calculate_keeper_reward(10_000_000_000, 5, 0, u64::MAX).unwrap(), 5_000_000);
+        // Raised to the tier minimum
+        assert_eq!(calculate_keeper_reward(1_000_000, 5, 500_000, 10_000_000).unwrap(), 500_000);
+        // Capped at the tier maximum
+        assert_eq!(calculate_keeper_reward(10_000_000_000, 5, 0, 2_000_000).unwrap(), 2_000_000);
+    }
+
+    #[test]
+    fn test_fee_discount() {
+        // 25% off an 8 USDC fee
+        assert_eq!(apply_fee_discount(8_000_000, 2_500).unwrap(), 6_000_000);
+        // No discount
+        assert_eq!(apply_fee_discount(8_000_000, 0).unwrap(), 8_000_000);
+        // Discounts above 100% make the fee free, never negative
+        assert_eq!(apply_fee_discount(8_000_000, 20_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_split_referral_fee() {
+        assert_eq!(split_referral_fee(1_000_000, 2_000, 1_000).unwrap(), (700_000, 200_000, 100_000));
+        assert_eq!(split_referral_fee(1_000_000, 0, 0).unwrap(), (1_000_000, 0, 0));
+        // Dust from rounding both shares down is kept by the protocol
+        assert_eq!(split_referral_fee(9, 5_000, 5_000).unwrap(), (1, 4, 4));
+        assert!(split_referral_fee(100, 8_000, 8_000).is_err());
+    }
+
+    #[test]
+    fn test_cross_margin_health() {
+        // Two positions allocating $500 each from $1,000 of collateral
+        assert_eq!(calculate_cross_margin_health(1_000_000_000, 0, 1_000_000_000), 100);
+        // A hedged pair: one leg's loss offset by the other's gain
+        assert_eq!(calculate_cross_margin_health(1_000_000_000, -300_000_000 + 250_000_000, 1_000_000_000), 95);
+        assert_eq!(calculate_cross_margin_health(1_000_000_000, -2_000_000_000, 1_000_000_000), 0);
+        assert_eq!(calculate_cross_margin_health(1_000_000_000, 0, 0), u8::MAX);
+    }
+
+    #[test]
+    fn test_portfolio_margin() {
+        let weights = [200, 300, 300, 400, 500, 500, 500, 500];
+        // $1,000 EURUSD long: EUR +1,000 -> 30
+        assert_eq!(calculate_portfolio_margin(&[0, 1_000_000_000, 0, 0, 0, 0, 0, 0], &weights, 1_000_000_000, 100), 30_000_000);
+        // EURUSD long against EURGBP short leaves only the GBP leg
+        let spread = [0, 0, 1_000_000_000, 0, 0, 0, 0, 0];
+        assert_eq!(calculate_portfolio_margin(&spread, &weights, 2_000_000_000, 100), 30_000_000);
+        // A fully offset book still carries the floor share of its gross notional
+        assert_eq!(calculate_portfolio_margin(&[0; 8], &weights, 2_000_000_000, 100), 20_000_000);
+        assert_eq!(calculate_portfolio_margin(&[0; 8], &weights, 0, 100), 0);
+    }
+
+    #[test]
+    fn test_scale_by_price_ratio() {
+        // USDJPY long from 150.00 to 151.50: 1% in JPY terms is worth 150/151.5 of that in USD
+        assert_eq!(scale_by_price_ratio(10_000_000, (15_000, -2), (15_150, -2)).unwrap(), 9_900_990);
+        // Exponents are normalized before dividing
+        assert_eq!(scale_by_price_ratio(1_000_000, (125_000_000, -8), (12_500, -4)).unwrap(), 1_000_000);
+        assert!(scale_by_price_ratio(1, (1, 0), (0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_usd_base_trigger_prices() {
+        use crate::state::Direction;
+
+        // 50% loss on notional: EURUSD-style halves the price, USDJPY-style needs 1.5x the move
+        let thresholds = [50, 35, 25, 15];
+        assert_eq!(calculate_trigger_prices(15_000, Direction::Long, false, &thresholds).unwrap()[0], 7_500);
+        assert_eq!(calculate_trigger_prices(15_000, Direction::Long, true, &thresholds).unwrap()[0], 10_000);
+        assert_eq!(calculate_trigger_prices(15_000, Direction::Short, true, &thresholds).unwrap()[0], 30_000);
+    }
+
+    #[test]
+    fn test_rolling_volume() {
+        let mut buckets = [0u64; 30];
+        buckets[(100 % 30) as usize] = 1_000;
+        buckets[(110 % 30) as usize] = 500;
+
+        // Both days are inside the window ending at day 110
+        assert_eq!(calculate_rolling_volume(&buckets, 110, 110), 1_500);
+        // Day 100 drops out of the window ending at day 130
+        assert_eq!(calculate_rolling_volume(&buckets, 110, 130), 500);
+        // Nothing left after a full idle window
+        assert_eq!(calculate_rolling_volume(&buckets, 110, 140), 0);
+
+        // Rolling forward clears the days that left the window
+        roll_volume_buckets(&mut buckets, 110, 130);
+        assert_eq!(buckets[(100 % 30) as usize], 0);
+        assert_eq!(calculate_rolling_volume(&buckets, 130, 130), 500);
+    }
+
+    #[test]
+    fn test_auction_fee_bps() {
+        // Starts low when the trigger is first crossed
+        assert_eq!(calculate_auction_fee_bps(1, 5, 300, 0), 1);
+        // Grows linearly
+        assert_eq!(calculate_auction_fee_bps(1, 5, 300, 150), 3);
+        // Capped after the ramp
+        assert_eq!(calculate_auction_fee_bps(1, 5, 300, 600), 5);
+        // Degenerate curve falls back to the cap
+        assert_eq!(calculate_auction_fee_bps(5, 5, 300, 0), 5);
+    }
+
+    #[test]
+    fn test_blended_entry_price() {
+        // Adding at the same price keeps the entry
+        assert_eq!(calculate_blended_entry_price(1_000, 100_000_000, 1_000, 100_000_000).unwrap(), 100_000_000);
+        // Equal notional at 1.00 and 1.10 blends to the harmonic mean (~1.0476)
+        assert_eq!(calculate_blended_entry_price(1_000, 100_000_000, 1_000, 110_000_000).unwrap(), 104_761_904);
+    }
+
+    #[test]
+    fn test_split_keeper_reward() {
+        // Fully covered by margin
+        assert_eq!(split_keeper_reward(100, 1_000, 0), (100, 0));
+        // Shortfall taken from insurance
+        assert_eq!(split_keeper_reward(100, 60, 1_000), (60, 40));
+        // Both sources exhausted
+        assert_eq!(split_keeper_reward(100, 60, 10), (60, 10));
+    }
+
+    #[test]
+    fn test_split_liquidation_margin() {
+        // Owner keeps whatever the penalty does not take
+        assert_eq!(split_liquidation_margin(1_000, 150), (150, 850));
+        // Penalty is capped by the remaining margin
+        assert_eq!(split_liquidation_margin(100, 150), (100, 0));
+        // No penalty configured: the owner gets all of it back
+        assert_eq!(split_liquidation_margin(1_000, 0), (0, 1_000));
+    }
+
+    #[test]
+    fn test_settlement_haircut() {
+        // Fully covered vault pays margin in full
+        assert_eq!(calculate_settlement_payout_bps(2_000, 1_000), 10_000);
+        assert_eq!(calculate_settlement_payout_bps(0, 0), 10_000);
+        // Vault short by 25% haircuts every claim by 25%
+        let payout_bps = calculate_settlement_payout_bps(750, 1_000);
+        assert_eq!(payout_bps, 7_500);
+        assert_eq!(apply_settlement_haircut(400_000_000, payout_bps).unwrap(), 300_000_000);
+    }
+
+    #[test]
+    fn test_price_distance_bps() {
+        // 1.00 -> 0.95 is a 5% move either way round
+        assert_eq!(calculate_price_distance_bps(100_000_000, 95_000_000), 500);
+        assert_eq!(calculate_price_distance_bps(100_000_000, 105_000_000), 500);
+        // Already at the target
+        assert_eq!(calculate_price_distance_bps(100_000_000, 100_000_000), 0);
+    }
+
+    #[test]
+    fn test_volatility_sample_and_ratio() {
+        // 0.5% move plus 0.1% confidence
+        assert_eq!(calculate_volatility_sample_bps(100_000_000, 99_500_000, 99_500, 200).unwrap(), 50 + 10);
+        // A single jump counts at most the cap
+        assert_eq!(calculate_volatility_sample_bps(100_000_000, 50_000_000, 0, 200).unwrap(), 200);
+        // First sample is confidence only
+        assert_eq!(calculate_volatility_sample_bps(0, 100_000_000, 100_000, 200).unwrap(), 10);
+        // Double the reference volatility
+        assert_eq!(calculate_volatility_ratio_bps(40, 20), 20_000);
+        // Adaptation disabled
+        assert_eq!(calculate_volatility_ratio_bps(40, 0), 10_000);
+    }
+
+    #[test]
+    fn test_execution_price() {
+        let price = 100_000_000;
+        // Buying 1M into a flat book with a 100M skew scale: 0.5% premium
+        assert_eq!(calculate_execution_price(price, 0, 1_000_000, 100_000_000, 100).unwrap(), 100_500_000);
+        // Selling into a 2M long skew earns a rebate: sold above the oracle (average skew 1.5M)
+        assert_eq!(calculate_execution_price(price, 2_000_000, -1_000_000, 100_000_000, 500).unwrap(), 101_500_000);
+        // Premium is capped
+        assert_eq!(calculate_execution_price(price, 50_000_000, 1_000_000, 100_000_000, 100).unwrap(), 101_000_000);
+        // Disabled
+        assert_eq!(calculate_execution_price(price, 50_000_000, 1_000_000, 0, 100).unwrap(), price);
+    }
+}
@@ -1,5 +1,13 @@
 pub mod math;
 pub mod pyth;
+pub mod outflow;
+pub mod scops;
+pub mod referral;
+pub mod cross_margin;
 
 pub use math::*;
 pub use pyth::*;
+pub use outflow::*;
+pub use scops::*;
+pub use referral::*;
+pub use cross_margin::*;
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::TradingError;
+use crate::state::{TradingConfig, PendingOutflow};
+
+/// Send USDC out of the protocol vault through the outflow rate limiter
+///
+/// Amounts that fit in the limiter's bucket are transferred now and must not come with
+/// a `pending_outflow`. Larger amounts need one and are queued there for delayed
+/// release, which is charged against the bucket as it refills.
+/// Returns true if the outflow was queued.
+#[allow(clippy::too_many_arguments)]
+pub fn route_usdc_outflow<'info>(
+    config: &mut Account<'info, TradingConfig>,
+    pending_outflow: Option<(&mut Account<'info, PendingOutflow>, u8)>,
+    usdc_vault: &Account<'info, TokenAccount>,
+    recipient: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    rent_payer: Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<bool> {
+    if !config.usdc_outflow_limiter.has_capacity(amount, now) {
+        let (pending, bump) = pending_outflow.ok_or(TradingError::OutflowRateLimited)?;
+        let (outflow_id, release_at) = config.queue_outflow(amount, now)?;
+
+        pending.outflow_id = outflow_id;
+        pending.recipient = recipient.key();
+        pending.amount = amount;
+        pending.release_at = release_at;
+        pending.rent_payer = rent_payer;
+        pending.bump = bump;
+        pending.version = PendingOutflow::VERSION;
+
+        msg!("Outflow queued: {} USDC, ID {}, releasable at {}", amount, pending.outflow_id, release_at);
+        return Ok(true);
+    }
+
+    // Queueing is reserved for outflows over the limit
+    require!(pending_outflow.is_none(), TradingError::OutflowWithinLimit);
+    require!(
+        config.usdc_outflow_limiter.try_consume(amount, now),
+        TradingError::OutflowRateLimited
+    );
+
+    let config_seeds = &[
+        TradingConfig::SEED_PREFIX,
+        &[config.bump],
+    ];
+    let signer_seeds = &[&config_seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: usdc_vault.to_account_info(),
+        to: recipient.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)?;
+
+    Ok(false)
+}
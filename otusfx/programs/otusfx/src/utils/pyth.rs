@@ -5,7 +5,7 @@
 
 use anchor_lang::prelude::*;
 use crate::error::TradingError;
-use crate::state::{FxPair, Direction};
+use crate::state::{FxPair, Direction, PairConfig, QuoteConversion};
 
 /// Pyth Solana Receiver Program ID (mainnet/devnet)
 /// Instructions use this to validate price feed account ownership
@@ -43,6 +43,7 @@ pub struct PythPrice {
 fn get_pyth_price(
     price_feed_account: &AccountInfo,
     max_staleness_sec: i64,
+    now: i64,
 ) -> Result<PythPrice> {
     // Verify it's owned by Pyth Receiver
     require!(
@@ -62,8 +63,7 @@ fn get_pyth_price(
     let publish_time = i64::from_le_bytes(data[28..36].try_into().unwrap());
 
     // Verify staleness
-    let clock = Clock::get()?;
-    let age = clock.unix_timestamp - publish_time;
+    let age = now - publish_time;
     require!(
         age <= max_staleness_sec && age >= 0,
         TradingError::StalePriceData
@@ -90,10 +90,29 @@ pub fn get_cached_price(
     max_confidence_bps: u16,
     clock: &Clock,
 ) -> Result<(i64, i32)> {
+    let pyth_price = get_cached_pyth_price(
+        price_feed_account,
+        pair,
+        max_staleness_slots,
+        max_confidence_bps,
+        clock,
+    )?;
+
+    Ok((pyth_price.price, pyth_price.expo))
+}
+
+/// Get cached price with its confidence and publish time (volatility tracking)
+pub fn get_cached_pyth_price(
+    price_feed_account: &AccountInfo,
+    pair: FxPair,
+    max_staleness_slots: u64,
+    max_confidence_bps: u16,
+    clock: &Clock,
+) -> Result<PythPrice> {
     // Convert slots to seconds (assuming ~400ms per slot)
     let max_staleness_sec = (max_staleness_slots as i64 * 400) / 1000;
     
-    let pyth_price = get_pyth_price(price_feed_account, max_staleness_sec)?;
+    let pyth_price = get_pyth_price(price_feed_account, max_staleness_sec, clock.unix_timestamp)?;
     
     // Validate confidence interval is acceptable
     // conf / price should be <= max_confidence_bps / 10000
@@ -107,7 +126,7 @@ pub fn get_cached_price(
     
     msg!("Pyth price for {:?}: {} (expo: {})", pair, pyth_price.price, pyth_price.expo);
     
-    Ok((pyth_price.price, pyth_price.expo))
+    Ok(pyth_price)
 }
 
 /// Map FX pair enum to string identifier for PDA seeds
@@ -148,6 +167,56 @@ pub fn get_pyth_feed_id(pair: FxPair) -> &'static str {
     }
 }
 
+/// Current price of a pair's quote conversion oracle (None if the pair doesn't need one)
+/// Cross pairs must pass the conversion pair's price cache (GBPUSD or USDJPY), which must be
+/// the quote feed bound to the pair's config
+pub fn get_quote_conversion_price(
+    pair_config: &PairConfig,
+    quote_price_cache: Option<&AccountInfo>,
+    max_staleness_slots: u64,
+    max_confidence_bps: u16,
+    clock: &Clock,
+) -> Result<Option<(i64, i32)>> {
+    let QuoteConversion::Oracle { pair: conversion_pair, .. } = pair_config.pair.quote_conversion() else {
+        return Ok(None);
+    };
+    let quote_price_cache = quote_price_cache.ok_or(TradingError::MissingQuoteOracle)?;
+    require!(
+        quote_price_cache.key() == pair_config.quote_price_feed,
+        TradingError::InvalidOracleAccount
+    );
+
+    get_cached_price(
+        quote_price_cache,
+        conversion_pair,
+        max_staleness_slots,
+        max_confidence_bps,
+        clock,
+    )
+    .map(Some)
+}
+
+/// Frozen settlement price of a pair's quote conversion oracle (None if the pair doesn't need one)
+pub fn get_quote_settlement_price(
+    pair: FxPair,
+    quote_pair_config: Option<&PairConfig>,
+) -> Result<Option<(i64, i32)>> {
+    let QuoteConversion::Oracle { pair: conversion_pair, .. } = pair.quote_conversion() else {
+        return Ok(None);
+    };
+    let quote_pair_config = quote_pair_config.ok_or(TradingError::MissingQuoteOracle)?;
+    require!(
+        quote_pair_config.pair == conversion_pair,
+        TradingError::MissingQuoteOracle
+    );
+    require!(
+        quote_pair_config.settlement_price > 0,
+        TradingError::SettlementPriceNotSet
+    );
+
+    Ok(Some((quote_pair_config.settlement_price, quote_pair_config.settlement_price_expo)))
+}
+
 /// Check if current price has crossed a trigger threshold
 pub fn has_crossed_trigger(
     current_price: i64,
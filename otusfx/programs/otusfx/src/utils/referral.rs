@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::error::TradingError;
+use crate::state::{TradingConfig, TraderAccount, ReferralCode};
+use super::split_referral_fee;
+
+/// Book a trading fee charged into the USDC vault
+///
+/// For a trader bound to a referral code, the referrer's share and the trader's rebate
+/// are credited as claimable balances and the rest accrues to the protocol. The bound
+/// code must be passed so referred traders cannot skip the split. Returns the
+/// (referrer, rebate) amounts credited.
+pub fn book_trading_fee(
+    config: &mut TradingConfig,
+    trader_account: &mut TraderAccount,
+    referral_code: Option<&mut Account<ReferralCode>>,
+    fee: u64,
+) -> Result<(u64, u64)> {
+    let (protocol, referrer, rebate) = if trader_account.has_referral() {
+        let referral_code = referral_code.ok_or(TradingError::InvalidReferralCode)?;
+        require_keys_eq!(
+            referral_code.key(),
+            trader_account.referral_code,
+            TradingError::InvalidReferralCode
+        );
+
+        let (protocol, referrer, rebate) = split_referral_fee(
+            fee,
+            config.referral_share_bps,
+            config.referral_rebate_bps,
+        )?;
+        referral_code.claimable = referral_code.claimable
+            .checked_add(referrer)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        referral_code.total_earned = referral_code.total_earned
+            .checked_add(referrer)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        trader_account.claimable_rebate = trader_account.claimable_rebate
+            .checked_add(rebate)
+            .ok_or(TradingError::ArithmeticOverflow)?;
+        (protocol, referrer, rebate)
+    } else {
+        (fee, 0, 0)
+    };
+
+    config.accrued_fees = config.accrued_fees
+        .checked_add(protocol)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+    config.total_referral_owed = config.total_referral_owed
+        .checked_add(referrer)
+        .ok_or(TradingError::ArithmeticOverflow)?
+        .checked_add(rebate)
+        .ok_or(TradingError::ArithmeticOverflow)?;
+
+    Ok((referrer, rebate))
+}
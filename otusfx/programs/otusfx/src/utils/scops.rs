@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use bootstrap_pool::state::UserDeposit;
+use crate::error::TradingError;
+use crate::state::TradingConfig;
+
+/// Resolve the trader's Scops NFT fee discount in basis points
+///
+/// The discount needs all three proofs: the trader's token account holding the NFT,
+/// the NFT mint and the trader's bootstrap `UserDeposit`, which records the mint it
+/// issued and the tier. Passing none of them means no discount.
+pub fn resolve_scops_discount_bps(
+    config: &TradingConfig,
+    trader: &Pubkey,
+    scops_nft: Option<&Account<TokenAccount>>,
+    scops_mint: Option<&Account<Mint>>,
+    scops_deposit: Option<&Account<UserDeposit>>,
+) -> Result<u16> {
+    let (nft, mint, deposit) = match (scops_nft, scops_mint, scops_deposit) {
+        (None, None, None) => return Ok(0),
+        (Some(nft), Some(mint), Some(deposit)) => (nft, mint, deposit),
+        _ => return err!(TradingError::InvalidScopsProof),
+    };
+
+    // The trader holds the NFT
+    require!(
+        nft.owner == *trader && nft.mint == mint.key() && nft.amount == 1,
+        TradingError::InvalidScopsProof
+    );
+
+    // The trader's own bootstrap deposit issued this NFT and carries the tier
+    // (the deposit is owned by the bootstrap pool, checked by `Account`)
+    require!(
+        deposit.user == *trader && deposit.has_minted_scops && deposit.scops_mint == mint.key(),
+        TradingError::InvalidScopsProof
+    );
+
+    Ok(config.scops_discount_bps[deposit.scops_tier as usize])
+}